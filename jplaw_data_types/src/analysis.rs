@@ -1,4 +1,4 @@
-use crate::article::{ArticleIndex, TextIndex};
+use crate::article::{ArticleIndex, TextIndex, TextSpan};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -7,9 +7,32 @@ use thiserror::Error;
 pub struct AnalysisResultInfo<T: std::fmt::Debug + Clone + std::hash::Hash + PartialEq + Eq> {
   pub article_index: ArticleIndex,
   pub text_index_opt: Option<TextIndex>,
+  /// 解析結果が見つかった文字列の範囲と元の文の要素
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub span: Option<TextSpan>,
   pub result: T,
 }
 
+impl<T: std::fmt::Debug + Clone + std::hash::Hash + PartialEq + Eq> AnalysisResultInfo<T> {
+  /// 文字列の範囲を持たない解析結果を作る
+  pub fn new(article_index: ArticleIndex, text_index_opt: Option<TextIndex>, result: T) -> Self {
+    Self {
+      article_index,
+      text_index_opt,
+      span: None,
+      result,
+    }
+  }
+
+  /// 解析結果が見つかった文字列の範囲を設定する
+  pub fn with_span(self, span: TextSpan) -> Self {
+    Self {
+      span: Some(span),
+      ..self
+    }
+  }
+}
+
 /// 解析結果を書き出すときの型
 #[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AnalysisError<
@@ -1,21 +1,34 @@
 //! 条文に関する型と関数の定義
 //!
 
-use crate::analysis::AnalysisResultInfo;
+use crate::{
+  analysis::AnalysisResultInfo,
+  law::Date,
+  normalize::{normalize, NormalizeOptions},
+  numeral::parse_number_ja,
+};
 use japanese_law_xml_schema::{
+  appdx::{AppdxFigContents, AppdxNoteContents, AppdxTableContents},
   article::{Article, ChapterContents, PartContents, SectionContents, SubsectionContents},
   article_number::ArticleNumber,
-  class::SentenceOrColumnOrTable,
-  contents::ContentsElement,
+  class::{Column, SentenceOrColumnOrTable},
+  contents::{ArithFormula, ContentsElement},
   law::{LawBody, MainProvisionContents},
+  line::{Line, LineContents},
+  list::{List, ListSentence},
   paragraph::{
-    Paragraph, Subitem1, Subitem10, Subitem2, Subitem3, Subitem4, Subitem5, Subitem6, Subitem7,
-    Subitem8, Subitem9,
+    Item, Paragraph, Subitem1, Subitem10, Subitem2, Subitem3, Subitem4, Subitem5, Subitem6,
+    Subitem7, Subitem8, Subitem9,
   },
-  sentence::SentenceElement,
+  remarks::{Remarks, RemarksContents},
+  sentence::{Sentence, SentenceElement, SentenceFunction},
+  structs::{FormatStruct, NoteStruct, QuoteStruct, Struct, StyleStruct},
   suppl_provision,
+  table::{Table, TableColumn, TableColumnContents},
+  text::{Ruby, Text, TextElement, TextWithWritingMode, WritingMode},
 };
 use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, fmt::Display, ops::Range, str::FromStr};
 
 /// 条文の位置を示す
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
@@ -39,56 +52,462 @@ pub struct ArticleIndex {
   /// 附則の場合は付加された改正法の法令番号
   #[serde(skip_serializing_if = "Option::is_none")]
   pub suppl_provision_name: Option<String>,
+  /// 別表などの場合はその情報
+  /// 条番号は0になる
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub appdx: Option<AppdxIndex>,
+}
+
+/// 別表・別記・様式などの条以外の要素の種類
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AppdxKind {
+  /// 別表
+  Table,
+  /// 別記
+  Note,
+  /// 様式
+  Style,
+  /// 書式
+  Format,
+  /// 別図
+  Fig,
+  /// 付録
+  Appdx,
+}
+
+impl Display for AppdxKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let s = match self {
+      AppdxKind::Table => "別表",
+      AppdxKind::Note => "別記",
+      AppdxKind::Style => "様式",
+      AppdxKind::Format => "書式",
+      AppdxKind::Fig => "別図",
+      AppdxKind::Appdx => "付録",
+    };
+    write!(f, "{s}")
+  }
+}
+
+/// 別表などを示す
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppdxIndex {
+  /// 種類
+  pub kind: AppdxKind,
+  /// 別表などの番号
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub num: Option<String>,
+  /// 「別表第一」のような見出し
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// 「（第二条関係）」のような関係条文
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub related_article_num: Option<String>,
+}
+
+/// 条を含む構造の種類
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum StructureKind {
+  /// 編
+  Part,
+  /// 章
+  Chapter,
+  /// 節
+  Section,
+  /// 款
+  Subsection,
+  /// 目
+  Division,
+}
+
+impl StructureKind {
+  /// 「第二章」のような見出しの番号部分を生成する
+  pub fn number_text(&self, num: &ArticleNumber) -> String {
+    match self {
+      StructureKind::Part => num.part_text(),
+      StructureKind::Chapter => num.chapter_text(),
+      StructureKind::Section => num.section_text(),
+      StructureKind::Subsection => num.subsection_text(),
+      StructureKind::Division => num.division_text(),
+    }
+  }
+}
+
+impl Display for StructureKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let s = match self {
+      StructureKind::Part => "編",
+      StructureKind::Chapter => "章",
+      StructureKind::Section => "節",
+      StructureKind::Subsection => "款",
+      StructureKind::Division => "目",
+    };
+    write!(f, "{s}")
+  }
+}
+
+/// 条番号を比較する
+/// 枝番号の無いものが先になる（第九条 < 第九条の二 < 第十条）
+pub fn cmp_article_number(a: &ArticleNumber, b: &ArticleNumber) -> Ordering {
+  (a.base_number, &a.eda_numbers, &a.range_end_numbers).cmp(&(
+    b.base_number,
+    &b.eda_numbers,
+    &b.range_end_numbers,
+  ))
+}
+
+fn cmp_article_number_opt(a: &Option<ArticleNumber>, b: &Option<ArticleNumber>) -> Ordering {
+  match (a, b) {
+    (None, None) => Ordering::Equal,
+    (None, Some(_)) => Ordering::Less,
+    (Some(_), None) => Ordering::Greater,
+    (Some(a), Some(b)) => cmp_article_number(a, b),
+  }
+}
+
+/// 「大正一五年四月二四日法律第六九号」のような改正法の法令番号から公布日と番号を得る
+fn suppl_provision_key(name: &str) -> (Option<Date>, Option<usize>) {
+  let date = name
+    .find('日')
+    .and_then(|i| Date::from_str(&name[..i + '日'.len_utf8()]).ok());
+  let num = name
+    .rfind('第')
+    .and_then(|i| name[i + '第'.len_utf8()..].strip_suffix('号'))
+    .and_then(parse_number_ja);
+  (date, num)
+}
+
+impl ArticleIndex {
+  /// 附則の条かどうか
+  pub fn is_suppl_provision(&self) -> bool {
+    self.suppl_provision_name.is_some()
+  }
+
+  /// 別表などかどうか
+  pub fn is_appdx(&self) -> bool {
+    self.appdx.is_some()
+  }
+
+  /// 指定した種類の構造の番号
+  pub fn structure_number(&self, kind: StructureKind) -> Option<&ArticleNumber> {
+    match kind {
+      StructureKind::Part => self.part_number.as_ref(),
+      StructureKind::Chapter => self.chapter_number.as_ref(),
+      StructureKind::Section => self.section_number.as_ref(),
+      StructureKind::Subsection => self.subsection_number.as_ref(),
+      StructureKind::Division => self.division_number.as_ref(),
+    }
+  }
+
+  /// 条を含む編・章・節・款・目を外側から順に返す
+  pub fn path(&self) -> impl Iterator<Item = (StructureKind, &ArticleNumber)> {
+    [
+      StructureKind::Part,
+      StructureKind::Chapter,
+      StructureKind::Section,
+      StructureKind::Subsection,
+      StructureKind::Division,
+    ]
+    .into_iter()
+    .filter_map(|kind| self.structure_number(kind).map(|num| (kind, num)))
+  }
+
+  /// 指定した構造の中にある条かどうか
+  /// `[(StructureKind::Chapter, 2), (StructureKind::Section, 3)]`のように指定すると「第二章第三節」の中の条が対象になる
+  /// 本則と附則の区別はしないため、必要に応じて[`ArticleIndex::is_suppl_provision`]と組み合わせる
+  pub fn is_within(&self, path: &[(StructureKind, ArticleNumber)]) -> bool {
+    path
+      .iter()
+      .all(|(kind, num)| self.structure_number(*kind) == Some(num))
+  }
+}
+
+impl PartialOrd for ArticleIndex {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// 同じ法令の中では条文の並び順になる
+/// 本則の後に附則が続き、附則同士は改正法の公布日と番号の順に並ぶ
+/// 附則の別表などはその附則の最後に、本則の別表などは全ての附則の後に並ぶ
+/// 条の範囲は`(start..=end).contains(&index)`のように調べられる
+impl Ord for ArticleIndex {
+  fn cmp(&self, other: &Self) -> Ordering {
+    let group = |index: &ArticleIndex| match (&index.suppl_provision_name, &index.appdx) {
+      (None, None) => 0,
+      (Some(_), _) => 1,
+      (None, Some(_)) => 2,
+    };
+    let suppl_key = |index: &ArticleIndex| {
+      index
+        .suppl_provision_name
+        .as_ref()
+        .map(|name| (suppl_provision_key(name), name.clone()))
+    };
+    let appdx_key = |index: &ArticleIndex| {
+      index.appdx.as_ref().map(|appdx| {
+        (
+          appdx.kind,
+          appdx.num.as_ref().and_then(|n| n.parse::<usize>().ok()),
+          appdx.num.clone(),
+          appdx.title.clone(),
+          appdx.related_article_num.clone(),
+        )
+      })
+    };
+    (&self.file_id, &self.law_name)
+      .cmp(&(&other.file_id, &other.law_name))
+      .then_with(|| group(self).cmp(&group(other)))
+      .then_with(|| suppl_key(self).cmp(&suppl_key(other)))
+      .then_with(|| appdx_key(self).cmp(&appdx_key(other)))
+      .then_with(|| cmp_article_number_opt(&self.part_number, &other.part_number))
+      .then_with(|| cmp_article_number_opt(&self.chapter_number, &other.chapter_number))
+      .then_with(|| cmp_article_number_opt(&self.section_number, &other.section_number))
+      .then_with(|| cmp_article_number_opt(&self.subsection_number, &other.subsection_number))
+      .then_with(|| cmp_article_number_opt(&self.division_number, &other.division_number))
+      .then_with(|| cmp_article_number(&self.article_number, &other.article_number))
+  }
+}
+
+#[test]
+fn check_article_index_ord() {
+  let num = |s: &str| ArticleNumber::from_num_str(s).unwrap();
+  let index = |chapter: Option<&str>, article: &str, suppl: Option<&str>| ArticleIndex {
+    file_id: "129AC0000000089".to_string(),
+    law_name: "民法".to_string(),
+    article_number: num(article),
+    part_number: None,
+    chapter_number: chapter.map(num),
+    section_number: None,
+    subsection_number: None,
+    division_number: None,
+    suppl_provision_name: suppl.map(|s| s.to_string()),
+    appdx: None,
+  };
+  let mut lst = vec![
+    index(None, "1", Some("平成一六年一二月一日法律第一四七号")),
+    index(Some("2"), "10", None),
+    index(None, "1", Some("昭和二二年一二月二二日法律第二二二号")),
+    index(Some("1"), "9_2", None),
+    index(None, "2", Some("")),
+    index(Some("1"), "9", None),
+    index(None, "1", Some("")),
+  ];
+  lst.sort();
+  assert_eq!(
+    lst,
+    vec![
+      index(Some("1"), "9", None),
+      index(Some("1"), "9_2", None),
+      index(Some("2"), "10", None),
+      index(None, "1", Some("")),
+      index(None, "2", Some("")),
+      index(None, "1", Some("昭和二二年一二月二二日法律第二二二号")),
+      index(None, "1", Some("平成一六年一二月一日法律第一四七号")),
+    ]
+  );
+  assert!(
+    (index(Some("1"), "9", None)..=index(Some("2"), "10", None)).contains(&index(
+      Some("1"),
+      "9_2",
+      None
+    ))
+  );
+  let article = index(Some("2"), "10", None);
+  assert!(!article.is_suppl_provision());
+  assert!(lst[3].is_suppl_provision());
+  assert!(article.is_within(&[(StructureKind::Chapter, num("2"))]));
+  assert!(!article.is_within(&[(StructureKind::Chapter, num("1"))]));
+  assert!(!article.is_within(&[(StructureKind::Section, num("2"))]));
+  assert_eq!(
+    article.path().collect::<Vec<_>>(),
+    vec![(StructureKind::Chapter, &num("2"))]
+  );
+  assert_eq!(StructureKind::Chapter.number_text(&num("2")), "第二章");
+}
+
+/// 編・章・節・款・目の見出し
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructureTitle {
+  /// 構造の種類
+  pub kind: StructureKind,
+  /// 番号
+  pub num: ArticleNumber,
+  /// 「第三章　株式会社の設立」のような見出し
+  pub title: String,
+}
+
+/// 条とそれを含む構造の見出しの組
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArticleEntry {
+  /// 条文の位置
+  pub article_index: ArticleIndex,
+  /// 条を含む編・章・節・款・目の見出しを外側から並べたもの
+  pub structure_titles: Vec<StructureTitle>,
+  /// 「（定義）」のような条見出し
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub caption: Option<String>,
+  /// 「第一条」のような条名
+  /// 条に属さない段落をまとめたものでは`None`になる
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+  /// 段落のリスト
+  pub paragraphs: Vec<Paragraph>,
+}
+
+impl ArticleEntry {
+  /// 構造の見出しと「第四条（成年）」のような条名を外側から並べる
+  pub fn breadcrumbs(&self) -> Vec<String> {
+    let mut v = self
+      .structure_titles
+      .iter()
+      .map(|t| t.title.clone())
+      .collect::<Vec<_>>();
+    if let Some(title) = &self.title {
+      v.push(format!(
+        "{title}{}",
+        self.caption.as_deref().unwrap_or_default()
+      ));
+    }
+    v
+  }
+}
+
+/// 条を走査する際の、外側の構造の情報
+#[derive(Debug, Clone)]
+struct StructureContext<'a> {
+  file_id: &'a str,
+  law_name: &'a str,
+  suppl_provision_name: Option<&'a str>,
+  structures: Vec<StructureTitle>,
+}
+
+impl<'a> StructureContext<'a> {
+  fn new(file_id: &'a str, law_name: &'a str, suppl_provision_name: Option<&'a str>) -> Self {
+    Self {
+      file_id,
+      law_name,
+      suppl_provision_name,
+      structures: Vec::new(),
+    }
+  }
+
+  fn with(&self, kind: StructureKind, num: &ArticleNumber, title: &Text) -> Self {
+    let mut ctx = self.clone();
+    ctx.structures.push(StructureTitle {
+      kind,
+      num: num.clone(),
+      title: title.to_string(),
+    });
+    ctx
+  }
+
+  fn number(&self, kind: StructureKind) -> Option<ArticleNumber> {
+    self
+      .structures
+      .iter()
+      .find(|t| t.kind == kind)
+      .map(|t| t.num.clone())
+  }
+
+  fn article_index(&self, article_number: &ArticleNumber) -> ArticleIndex {
+    ArticleIndex {
+      file_id: self.file_id.to_string(),
+      law_name: self.law_name.to_string(),
+      article_number: article_number.clone(),
+      part_number: self.number(StructureKind::Part),
+      chapter_number: self.number(StructureKind::Chapter),
+      section_number: self.number(StructureKind::Section),
+      subsection_number: self.number(StructureKind::Subsection),
+      division_number: self.number(StructureKind::Division),
+      suppl_provision_name: self.suppl_provision_name.map(|s| s.to_string()),
+      appdx: None,
+    }
+  }
+
+  fn article(&self, article: &Article) -> ArticleEntry {
+    ArticleEntry {
+      article_index: self.article_index(&article.num),
+      structure_titles: self.structures.clone(),
+      caption: article.caption.as_ref().map(|c| c.text.to_string()),
+      title: Some(article.title.to_string()),
+      paragraphs: article.paragraph.clone(),
+    }
+  }
+
+  /// 条に属さない段落をまとめて条番号0の条とする
+  fn paragraphs(&self, paragraphs: Vec<Paragraph>) -> ArticleEntry {
+    ArticleEntry {
+      article_index: self.article_index(&ArticleNumber::zero()),
+      structure_titles: self.structures.clone(),
+      caption: None,
+      title: None,
+      paragraphs,
+    }
+  }
+
+  fn appdx(
+    &self,
+    kind: AppdxKind,
+    num: &Option<String>,
+    title: Option<String>,
+    related_article_num: &Option<Text>,
+    contents: AppdxContents,
+    remarks: &Option<Remarks>,
+  ) -> AppdxEntry {
+    let mut article_index = self.article_index(&ArticleNumber::zero());
+    article_index.appdx = Some(AppdxIndex {
+      kind,
+      num: num.clone(),
+      title,
+      related_article_num: related_article_num.as_ref().map(|t| t.to_string()),
+    });
+    AppdxEntry {
+      article_index,
+      contents,
+      remarks: remarks.clone(),
+    }
+  }
 }
 
 /// lawbodyから条文のリストを得る
+/// 別表などは[`AppdxContents::to_paragraph`]で一つの段落にまとめて含める
 pub fn article_list_from_lawbody(
   file_id: &str,
   law_name: &str,
   lawbody: &LawBody,
 ) -> Vec<AnalysisResultInfo<Vec<Paragraph>>> {
+  article_entry_list_from_lawbody(file_id, law_name, lawbody)
+    .into_iter()
+    .map(|entry| AnalysisResultInfo::new(entry.article_index, None, entry.paragraphs))
+    .collect()
+}
+
+/// lawbodyから条文のリストを、編・章・節・款・目の見出しと条見出しとともに得る
+/// 別表などは条文の並び順の位置に含める
+pub fn article_entry_list_from_lawbody(
+  file_id: &str,
+  law_name: &str,
+  lawbody: &LawBody,
+) -> Vec<ArticleEntry> {
   let mut v = Vec::new();
   let mut para_v = Vec::new();
+  let ctx = StructureContext::new(file_id, law_name, None);
   for main in &lawbody.main_provision.children {
     match main {
-      MainProvisionContents::Article(t) => {
-        let article_index = ArticleIndex {
-          file_id: file_id.to_string(),
-          law_name: law_name.to_string(),
-          article_number: t.num.clone(),
-          part_number: None,
-          chapter_number: None,
-          section_number: None,
-          subsection_number: None,
-          division_number: None,
-          suppl_provision_name: None,
-        };
-        v.push(AnalysisResultInfo {
-          article_index,
-          text_index_opt: None,
-          result: t.paragraph.clone(),
-        })
-      }
+      MainProvisionContents::Article(t) => v.push(ctx.article(t)),
       MainProvisionContents::Part(t) => {
-        let mut v2 = article_list_from_part(file_id, law_name, Some(&t.num), &t.children);
-        v.append(&mut v2);
+        let ctx = ctx.with(StructureKind::Part, &t.num, &t.part_title);
+        article_entry_list_from_part(&ctx, &t.children, &mut v);
       }
       MainProvisionContents::Chapter(t) => {
-        let mut v2 =
-          article_list_from_chapter(file_id, law_name, None, Some(&t.num), None, &t.children);
-        v.append(&mut v2);
+        let ctx = ctx.with(StructureKind::Chapter, &t.num, &t.chapter_title);
+        article_entry_list_from_chapter(&ctx, &t.children, &mut v);
       }
       MainProvisionContents::Section(t) => {
-        let mut v2 = article_list_from_section(
-          file_id,
-          law_name,
-          None,
-          None,
-          Some(&t.num),
-          None,
-          &t.children,
-        );
-        v.append(&mut v2);
+        let ctx = ctx.with(StructureKind::Section, &t.num, &t.section_title);
+        article_entry_list_from_section(&ctx, &t.children, &mut v);
       }
       MainProvisionContents::Paragraph(t) => {
         para_v.push(t.clone());
@@ -96,401 +515,1293 @@ pub fn article_list_from_lawbody(
     }
   }
   if !para_v.is_empty() {
-    let article_index = ArticleIndex {
-      file_id: file_id.to_string(),
-      law_name: law_name.to_string(),
-      article_number: ArticleNumber::zero(),
-      part_number: None,
-      chapter_number: None,
-      section_number: None,
-      subsection_number: None,
-      division_number: None,
-      suppl_provision_name: None,
-    };
-    v.push(AnalysisResultInfo {
-      article_index,
-      text_index_opt: None,
-      result: para_v,
-    })
+    v.push(ctx.paragraphs(para_v));
   }
 
   for suppl_provision in &lawbody.suppl_provision {
-    let suppl_provision_name = &suppl_provision.amend_law_num.clone().unwrap_or_default();
+    let suppl_provision_name = suppl_provision.amend_law_num.as_deref().unwrap_or_default();
+    let ctx = StructureContext::new(file_id, law_name, Some(suppl_provision_name));
     let mut suppl_para_v = Vec::new();
     for se in suppl_provision.children.iter() {
       match se {
-        suppl_provision::SupplProvisionChildrenElement::Article(t) => {
-          let article_index = ArticleIndex {
-            file_id: file_id.to_string(),
-            law_name: law_name.to_string(),
-            article_number: t.num.clone(),
-            part_number: None,
-            chapter_number: None,
-            section_number: None,
-            subsection_number: None,
-            division_number: None,
-            suppl_provision_name: Some(suppl_provision_name.clone()),
-          };
-          v.push(AnalysisResultInfo {
-            article_index,
-            text_index_opt: None,
-            result: t.paragraph.clone(),
-          })
-        }
+        suppl_provision::SupplProvisionChildrenElement::Article(t) => v.push(ctx.article(t)),
         suppl_provision::SupplProvisionChildrenElement::Chapter(t) => {
-          let mut v2 = article_list_from_chapter(
-            file_id,
-            law_name,
-            None,
-            Some(&t.num),
-            Some(suppl_provision_name),
-            &t.children,
-          );
-          v.append(&mut v2);
+          let ctx = ctx.with(StructureKind::Chapter, &t.num, &t.chapter_title);
+          article_entry_list_from_chapter(&ctx, &t.children, &mut v);
         }
         suppl_provision::SupplProvisionChildrenElement::Paragraph(t) => {
           suppl_para_v.push(t.clone());
         }
-        _ => (),
+        suppl_provision::SupplProvisionChildrenElement::SupplProvisionAppdxTable(_)
+        | suppl_provision::SupplProvisionChildrenElement::SupplProvisionAppdxStyle(_)
+        | suppl_provision::SupplProvisionChildrenElement::SupplProvisionAppdx(_) => (),
       }
     }
     if !suppl_para_v.is_empty() {
-      let article_index = ArticleIndex {
-        file_id: file_id.to_string(),
-        law_name: law_name.to_string(),
-        article_number: ArticleNumber::zero(),
-        part_number: None,
-        chapter_number: None,
-        section_number: None,
-        subsection_number: None,
-        division_number: None,
-        suppl_provision_name: Some(suppl_provision_name.clone()),
-      };
-      v.push(AnalysisResultInfo {
-        article_index,
-        text_index_opt: None,
-        result: suppl_para_v,
-      })
+      v.push(ctx.paragraphs(suppl_para_v));
     }
+    v.extend(
+      appdx_list_from_suppl_provision(&ctx, &suppl_provision.children)
+        .into_iter()
+        .map(ArticleEntry::from),
+    );
   }
+  let ctx = StructureContext::new(file_id, law_name, None);
+  v.extend(
+    appdx_list_from_main(&ctx, lawbody)
+      .into_iter()
+      .map(ArticleEntry::from),
+  );
   v
 }
 
-fn article_list_from_part(
-  file_id: &str,
-  law_name: &str,
-  part_number: Option<&ArticleNumber>,
+fn article_entry_list_from_part(
+  ctx: &StructureContext,
   lst: &[PartContents],
-) -> Vec<AnalysisResultInfo<Vec<Paragraph>>> {
-  let mut v = Vec::new();
+  v: &mut Vec<ArticleEntry>,
+) {
   for contents in lst.iter() {
     match contents {
       PartContents::Chapter(t) => {
-        let mut v2 = article_list_from_chapter(
-          file_id,
-          law_name,
-          part_number,
-          Some(&t.num),
-          None,
-          &t.children,
-        );
-        v.append(&mut v2)
-      }
-      PartContents::Article(t) => {
-        let article_index = ArticleIndex {
-          file_id: file_id.to_string(),
-          law_name: law_name.to_string(),
-          article_number: t.num.clone(),
-          part_number: part_number.cloned(),
-          chapter_number: None,
-          section_number: None,
-          subsection_number: None,
-          division_number: None,
-          suppl_provision_name: None,
-        };
-        v.push(AnalysisResultInfo {
-          article_index,
-          text_index_opt: None,
-          result: t.paragraph.clone(),
-        })
+        let ctx = ctx.with(StructureKind::Chapter, &t.num, &t.chapter_title);
+        article_entry_list_from_chapter(&ctx, &t.children, v);
       }
+      PartContents::Article(t) => v.push(ctx.article(t)),
     }
   }
-  v
 }
 
-fn article_list_from_chapter(
-  file_id: &str,
-  law_name: &str,
-  part_number: Option<&ArticleNumber>,
-  chapter_number: Option<&ArticleNumber>,
-  suppl_provision_name: Option<&String>,
+fn article_entry_list_from_chapter(
+  ctx: &StructureContext,
   lst: &[ChapterContents],
-) -> Vec<AnalysisResultInfo<Vec<Paragraph>>> {
-  let mut v = Vec::new();
+  v: &mut Vec<ArticleEntry>,
+) {
   for contents in lst.iter() {
     match contents {
       ChapterContents::Section(t) => {
-        let mut v2 = article_list_from_section(
-          file_id,
-          law_name,
-          part_number,
-          chapter_number,
-          Some(&t.num),
-          suppl_provision_name,
-          &t.children,
-        );
-        v.append(&mut v2)
-      }
-      ChapterContents::Article(t) => {
-        let article_index = ArticleIndex {
-          file_id: file_id.to_string(),
-          law_name: law_name.to_string(),
-          article_number: t.num.clone(),
-          part_number: part_number.cloned(),
-          chapter_number: chapter_number.cloned(),
-          section_number: None,
-          subsection_number: None,
-          division_number: None,
-          suppl_provision_name: suppl_provision_name.cloned(),
-        };
-        v.push(AnalysisResultInfo {
-          article_index,
-          text_index_opt: None,
-          result: t.paragraph.clone(),
-        })
+        let ctx = ctx.with(StructureKind::Section, &t.num, &t.section_title);
+        article_entry_list_from_section(&ctx, &t.children, v);
       }
+      ChapterContents::Article(t) => v.push(ctx.article(t)),
     }
   }
-  v
 }
 
-fn article_list_from_section(
-  file_id: &str,
-  law_name: &str,
-  part_number: Option<&ArticleNumber>,
-  chapter_number: Option<&ArticleNumber>,
-  seciton_number: Option<&ArticleNumber>,
-  suppl_provision_name: Option<&String>,
+fn article_entry_list_from_section(
+  ctx: &StructureContext,
   lst: &[SectionContents],
-) -> Vec<AnalysisResultInfo<Vec<Paragraph>>> {
-  let mut v = Vec::new();
+  v: &mut Vec<ArticleEntry>,
+) {
   for contents in lst.iter() {
     match contents {
       SectionContents::Subsection(t) => {
-        let mut v2 = article_list_from_subsection(
-          file_id,
-          law_name,
-          part_number,
-          chapter_number,
-          seciton_number,
-          Some(&t.num),
-          suppl_provision_name,
-          &t.children,
-        );
-        v.append(&mut v2)
+        let ctx = ctx.with(StructureKind::Subsection, &t.num, &t.subsection_title);
+        article_entry_list_from_subsection(&ctx, &t.children, v);
       }
       SectionContents::Division(t) => {
-        let mut v2 = article_list_from_division(
-          file_id,
-          law_name,
-          part_number,
-          chapter_number,
-          seciton_number,
-          None,
+        let ctx = ctx.with(StructureKind::Division, &t.num, &t.division_title);
+        v.extend(t.children.iter().map(|t| ctx.article(t)));
+      }
+      SectionContents::Article(t) => v.push(ctx.article(t)),
+    }
+  }
+}
+
+fn article_entry_list_from_subsection(
+  ctx: &StructureContext,
+  lst: &[SubsectionContents],
+  v: &mut Vec<ArticleEntry>,
+) {
+  for contents in lst.iter() {
+    match contents {
+      SubsectionContents::Division(t) => {
+        let ctx = ctx.with(StructureKind::Division, &t.num, &t.division_title);
+        v.extend(t.children.iter().map(|t| ctx.article(t)));
+      }
+      SubsectionContents::Article(t) => v.push(ctx.article(t)),
+    }
+  }
+}
+
+/// 別表などの中身
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AppdxContents {
+  /// 別表の表と号
+  Table(Vec<AppdxTableContents>),
+  /// 別記の記・図・表
+  Note(Vec<AppdxNoteContents>),
+  /// 様式
+  Style(Vec<StyleStruct>),
+  /// 書式
+  Format(Vec<FormatStruct>),
+  /// 別図の図と表
+  Fig(Vec<AppdxFigContents>),
+  /// 付録の数式
+  ArithFormula(Vec<ArithFormula>),
+}
+
+impl AppdxContents {
+  /// 条文と同じように扱えるように、中身を一つの段落にまとめる
+  /// 号は段落の子に、表・図・様式は`struct_list`に、数式や記の文は文に入れる
+  /// 書式の中身はスキーマのクレートの外から読めないため含めない
+  pub fn to_paragraph(&self) -> Paragraph {
+    let mut builder = AppdxParagraphBuilder::default();
+    builder.push_appdx_contents(self);
+    builder.build()
+  }
+}
+
+/// 別表などの中身を一つの段落に詰める
+#[derive(Default)]
+struct AppdxParagraphBuilder {
+  sentence: Vec<Sentence>,
+  struct_list: Vec<Struct>,
+  children: Vec<Item>,
+  inline: Vec<SentenceElement>,
+}
+
+impl AppdxParagraphBuilder {
+  fn push_appdx_contents(&mut self, contents: &AppdxContents) {
+    match contents {
+      AppdxContents::Table(lst) => {
+        for t in lst.iter() {
+          match t {
+            AppdxTableContents::TableStruct(t) => self.push_struct(Struct::TableStruct(t.clone())),
+            AppdxTableContents::Item(t) => self.push_item(t),
+          }
+        }
+      }
+      AppdxContents::Note(lst) => {
+        for t in lst.iter() {
+          match t {
+            AppdxNoteContents::TableStruct(t) => self.push_struct(Struct::TableStruct(t.clone())),
+            AppdxNoteContents::FigStruct(t) => self.push_struct(Struct::FigStruct(t.clone())),
+            AppdxNoteContents::NoteStruct(t) => self.push_note_struct(t),
+          }
+        }
+      }
+      AppdxContents::Style(lst) => {
+        for t in lst.iter() {
+          self.push_struct(Struct::StyleStruct(t.clone()));
+        }
+      }
+      // 書式の中身はスキーマのクレートの外から読めないため含めない
+      AppdxContents::Format(_) => (),
+      AppdxContents::Fig(lst) => {
+        for t in lst.iter() {
+          match t {
+            AppdxFigContents::TableStruct(t) => self.push_struct(Struct::TableStruct(t.clone())),
+            AppdxFigContents::FigStruct(t) => self.push_struct(Struct::FigStruct(t.clone())),
+          }
+        }
+      }
+      AppdxContents::ArithFormula(lst) => {
+        for t in lst.iter() {
+          self.flush_inline();
+          self.sentence.push(Sentence {
+            contents: vec![SentenceElement::ArithFormula(t.clone())],
+            num: t.num,
+            function: None,
+            indent: None,
+            writing_mode: WritingMode::Vertical,
+          });
+        }
+      }
+    }
+  }
+
+  fn push_note_struct(&mut self, t: &NoteStruct) {
+    for r in t.title_remarks.iter() {
+      self.push_remarks(r);
+    }
+    self.push_contents(&t.note.contentes.contents);
+    for r in t.note_remarks.iter() {
+      self.push_remarks(r);
+    }
+  }
+
+  /// 備考の文と号を入れる
+  fn push_remarks(&mut self, remarks: &Remarks) {
+    for r in remarks.children.iter() {
+      match r {
+        RemarksContents::Sentence(t) => self.push_sentence(t),
+        RemarksContents::Item(t) => self.push_item(t),
+      }
+    }
+  }
+
+  /// 記の中身を入れる
+  /// 文中に現れる要素は続くものをまとめて一つの文にする
+  fn push_contents(&mut self, lst: &[ContentsElement]) {
+    for c in lst.iter() {
+      match c {
+        ContentsElement::String(t) => self.inline.push(SentenceElement::String(t.clone())),
+        ContentsElement::Ruby(t) => self.inline.push(SentenceElement::Ruby(t.clone())),
+        ContentsElement::Sup(t) => self.inline.push(SentenceElement::Sup(t.clone())),
+        ContentsElement::Sub(t) => self.inline.push(SentenceElement::Sub(t.clone())),
+        ContentsElement::Line(t) => self.inline.push(SentenceElement::Line(t.clone())),
+        ContentsElement::QuoteStruct(t) => {
+          self.inline.push(SentenceElement::QuoteStruct(t.clone()))
+        }
+        ContentsElement::ArithFormula(t) => {
+          self.inline.push(SentenceElement::ArithFormula(t.clone()))
+        }
+        ContentsElement::Sentence(t) => self.push_sentence(t),
+        ContentsElement::Paragraph(t) => {
+          for t in t.sentence.iter() {
+            self.push_sentence(t);
+          }
+          for t in t.struct_list.iter() {
+            self.push_struct(t.clone());
+          }
+          for t in t.children.iter() {
+            self.push_item(t);
+          }
+        }
+        ContentsElement::Item(t) => self.push_item(t),
+        ContentsElement::TableStruct(t) => self.push_struct(Struct::TableStruct(t.clone())),
+        ContentsElement::FigStruct(t) => self.push_struct(Struct::FigStruct(t.clone())),
+        ContentsElement::StyleStruct(t) => self.push_struct(Struct::StyleStruct(t.clone())),
+        ContentsElement::List(t) => self.push_struct(Struct::List(t.clone())),
+        ContentsElement::NoteStruct(t) => self.push_note_struct(t),
+        ContentsElement::Remarks(t) => self.push_remarks(t),
+        // 段落で表せない細分や表の一部などは含めない
+        _ => (),
+      }
+    }
+  }
+
+  fn push_sentence(&mut self, t: &Sentence) {
+    self.flush_inline();
+    self.sentence.push(t.clone());
+  }
+
+  fn push_struct(&mut self, t: Struct) {
+    self.flush_inline();
+    self.struct_list.push(t);
+  }
+
+  fn push_item(&mut self, t: &Item) {
+    self.flush_inline();
+    self.children.push(t.clone());
+  }
+
+  fn flush_inline(&mut self) {
+    if self.inline.is_empty() {
+      return;
+    }
+    let contents = std::mem::take(&mut self.inline);
+    self.sentence.push(Sentence {
+      contents,
+      num: Some(self.sentence.len() + 1),
+      function: None,
+      indent: None,
+      writing_mode: WritingMode::Vertical,
+    });
+  }
+
+  fn build(mut self) -> Paragraph {
+    self.flush_inline();
+    Paragraph {
+      caption: None,
+      paragraph_num: Text {
+        contents: Vec::new(),
+      },
+      amend_provision: Vec::new(),
+      class: Vec::new(),
+      sentence: self.sentence,
+      struct_list: self.struct_list,
+      children: self.children,
+      num: ArticleNumber::zero(),
+      old_style: false,
+      old_num: false,
+      hide: false,
+    }
+  }
+}
+
+/// 別表などとその中身の組
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppdxEntry {
+  /// 別表などの位置
+  /// `appdx`に別表などの情報が入る
+  pub article_index: ArticleIndex,
+  /// 中身
+  pub contents: AppdxContents,
+  /// 備考
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remarks: Option<Remarks>,
+}
+
+impl AppdxEntry {
+  /// 中身と備考を一つの段落にまとめる
+  /// 備考の文と号は中身の後に続ける
+  pub fn to_paragraph(&self) -> Paragraph {
+    let mut builder = AppdxParagraphBuilder::default();
+    builder.push_appdx_contents(&self.contents);
+    if let Some(remarks) = &self.remarks {
+      builder.push_remarks(remarks);
+    }
+    builder.build()
+  }
+}
+
+impl From<AppdxEntry> for ArticleEntry {
+  fn from(entry: AppdxEntry) -> Self {
+    ArticleEntry {
+      structure_titles: Vec::new(),
+      caption: None,
+      title: entry
+        .article_index
+        .appdx
+        .as_ref()
+        .and_then(|appdx| appdx.title.clone()),
+      paragraphs: vec![entry.to_paragraph()],
+      article_index: entry.article_index,
+    }
+  }
+}
+
+/// lawbodyから本則と附則の別表・別記・様式などのリストを得る
+/// 附則のものを附則の順に並べた後に本則のものを並べる
+pub fn appdx_list_from_lawbody(
+  file_id: &str,
+  law_name: &str,
+  lawbody: &LawBody,
+) -> Vec<AppdxEntry> {
+  let mut v = Vec::new();
+  for suppl_provision in &lawbody.suppl_provision {
+    let suppl_provision_name = suppl_provision.amend_law_num.as_deref().unwrap_or_default();
+    let ctx = StructureContext::new(file_id, law_name, Some(suppl_provision_name));
+    v.append(&mut appdx_list_from_suppl_provision(
+      &ctx,
+      &suppl_provision.children,
+    ));
+  }
+  let ctx = StructureContext::new(file_id, law_name, None);
+  v.append(&mut appdx_list_from_main(&ctx, lawbody));
+  v
+}
+
+fn appdx_list_from_main(ctx: &StructureContext, lawbody: &LawBody) -> Vec<AppdxEntry> {
+  let title = |t: &Option<TextWithWritingMode>| t.as_ref().map(|t| t.to_string());
+  let mut v = Vec::new();
+  for t in lawbody.appdx_table.iter() {
+    v.push(ctx.appdx(
+      AppdxKind::Table,
+      &t.num,
+      title(&t.title),
+      &t.related_article_num,
+      AppdxContents::Table(t.children.clone()),
+      &t.remarks,
+    ));
+  }
+  for t in lawbody.appdx_note.iter() {
+    v.push(ctx.appdx(
+      AppdxKind::Note,
+      &t.num,
+      title(&t.title),
+      &t.related_article_num,
+      AppdxContents::Note(t.children.clone()),
+      &t.remarks,
+    ));
+  }
+  for t in lawbody.appdx_style.iter() {
+    v.push(ctx.appdx(
+      AppdxKind::Style,
+      &t.num,
+      title(&t.title),
+      &t.related_article_num,
+      AppdxContents::Style(t.children.clone()),
+      &t.remarks,
+    ));
+  }
+  for t in lawbody.appdx_format.iter() {
+    v.push(ctx.appdx(
+      AppdxKind::Format,
+      &t.num,
+      title(&t.title),
+      &t.related_article_num,
+      AppdxContents::Format(t.children.clone()),
+      &t.remarks,
+    ));
+  }
+  for t in lawbody.appdx_fig.iter() {
+    v.push(ctx.appdx(
+      AppdxKind::Fig,
+      &t.num,
+      title(&t.title),
+      &t.related_article_num,
+      AppdxContents::Fig(t.children.clone()),
+      &None,
+    ));
+  }
+  for t in lawbody.appdx.iter() {
+    v.push(ctx.appdx(
+      AppdxKind::Appdx,
+      &None,
+      t.arith_formula_num.as_ref().map(|t| t.to_string()),
+      &t.related_article_num,
+      AppdxContents::ArithFormula(t.arith_formula.clone()),
+      &t.remarks,
+    ));
+  }
+  v
+}
+
+fn appdx_list_from_suppl_provision(
+  ctx: &StructureContext,
+  lst: &[suppl_provision::SupplProvisionChildrenElement],
+) -> Vec<AppdxEntry> {
+  let mut v = Vec::new();
+  for se in lst.iter() {
+    match se {
+      suppl_provision::SupplProvisionChildrenElement::SupplProvisionAppdxTable(t) => v.push(
+        ctx.appdx(
+          AppdxKind::Table,
           &t.num,
-          suppl_provision_name,
-          &t.children,
-        );
-        v.append(&mut v2)
-      }
-      SectionContents::Article(t) => {
-        let article_index = ArticleIndex {
-          file_id: file_id.to_string(),
-          law_name: law_name.to_string(),
-          article_number: t.num.clone(),
-          part_number: part_number.cloned(),
-          chapter_number: chapter_number.cloned(),
-          section_number: seciton_number.cloned(),
-          subsection_number: None,
-          division_number: None,
-          suppl_provision_name: suppl_provision_name.cloned(),
-        };
-        v.push(AnalysisResultInfo {
-          article_index,
-          text_index_opt: None,
-          result: t.paragraph.clone(),
-        })
+          Some(t.title.to_string()),
+          &t.related_article_num,
+          AppdxContents::Table(
+            t.table_struct
+              .iter()
+              .cloned()
+              .map(AppdxTableContents::TableStruct)
+              .collect(),
+          ),
+          &None,
+        ),
+      ),
+      suppl_provision::SupplProvisionChildrenElement::SupplProvisionAppdxStyle(t) => {
+        v.push(ctx.appdx(
+          AppdxKind::Style,
+          &t.num,
+          Some(t.title.to_string()),
+          &t.related_article_num,
+          AppdxContents::Style(t.style_struct.clone()),
+          &None,
+        ))
       }
+      suppl_provision::SupplProvisionChildrenElement::SupplProvisionAppdx(t) => v.push(ctx.appdx(
+        AppdxKind::Appdx,
+        &t.num,
+        t.arith_formula_num.as_ref().map(|t| t.to_string()),
+        &t.related_article_num,
+        AppdxContents::ArithFormula(t.arith_formula.clone()),
+        &None,
+      )),
+      suppl_provision::SupplProvisionChildrenElement::Article(_)
+      | suppl_provision::SupplProvisionChildrenElement::Chapter(_)
+      | suppl_provision::SupplProvisionChildrenElement::Paragraph(_) => (),
+    }
+  }
+  v
+}
+
+/// テスト用に「テスト法」の`LawBody`の中身を読み込む
+#[cfg(test)]
+fn test_law(body: &str) -> japanese_law_xml_schema::law::Law {
+  let xml = format!(
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<Law Era="Reiwa" Lang="ja" LawType="Act" Num="1" Year="5">
+  <LawNum>令和五年法律第一号</LawNum>
+  <LawBody>
+    <LawTitle>テスト法</LawTitle>
+{body}
+  </LawBody>
+</Law>"#
+  );
+  japanese_law_xml_schema::parse_xml(xml.as_bytes()).unwrap()
+}
+
+/// テスト用に本則の一つの段落を読み込む
+#[cfg(test)]
+fn test_paragraph(paragraph: &str) -> Paragraph {
+  let law = test_law(&format!("<MainProvision>{paragraph}</MainProvision>"));
+  let MainProvisionContents::Paragraph(para) = &law.law_body.main_provision.children[0] else {
+    panic!()
+  };
+  para.clone()
+}
+
+#[test]
+fn check_appdx_list_from_lawbody() {
+  let law = test_law(
+    r#"<MainProvision>
+  <Article Num="1">
+    <ArticleTitle>第一条</ArticleTitle>
+    <Paragraph Num="1">
+      <ParagraphNum/>
+      <ParagraphSentence><Sentence Num="1">別表第一に掲げる者とする。</Sentence></ParagraphSentence>
+    </Paragraph>
+  </Article>
+</MainProvision>
+<SupplProvision>
+  <SupplProvisionLabel>附　則</SupplProvisionLabel>
+  <Paragraph Num="1">
+    <ParagraphNum/>
+    <ParagraphSentence><Sentence Num="1">この法律は、公布の日から施行する。</Sentence></ParagraphSentence>
+  </Paragraph>
+</SupplProvision>
+<SupplProvision AmendLawNum="令和六年三月一日法律第二号">
+  <SupplProvisionLabel>附　則</SupplProvisionLabel>
+  <SupplProvisionAppdxTable Num="1">
+    <SupplProvisionAppdxTableTitle>附則別表第一</SupplProvisionAppdxTableTitle>
+    <RelatedArticleNum>（附則第二条関係）</RelatedArticleNum>
+    <TableStruct>
+      <Table>
+        <TableRow><TableColumn><Sentence Num="1">甲</Sentence></TableColumn></TableRow>
+      </Table>
+    </TableStruct>
+  </SupplProvisionAppdxTable>
+</SupplProvision>
+<AppdxTable Num="1">
+  <AppdxTableTitle>別表第一</AppdxTableTitle>
+  <RelatedArticleNum>（第一条関係）</RelatedArticleNum>
+  <TableStruct>
+    <Table>
+      <TableRow><TableColumn><Sentence Num="1">乙</Sentence></TableColumn></TableRow>
+    </Table>
+  </TableStruct>
+</AppdxTable>"#,
+  );
+  let lst = appdx_list_from_lawbody("505AC0000000001", "テスト法", &law.law_body);
+  let appdx = |title: &str, related: &str| AppdxIndex {
+    kind: AppdxKind::Table,
+    num: Some("1".to_string()),
+    title: Some(title.to_string()),
+    related_article_num: Some(related.to_string()),
+  };
+  assert_eq!(
+    lst
+      .iter()
+      .map(|e| (
+        e.article_index.suppl_provision_name.clone(),
+        e.article_index.appdx.clone()
+      ))
+      .collect::<Vec<_>>(),
+    vec![
+      (
+        Some("令和六年三月一日法律第二号".to_string()),
+        Some(appdx("附則別表第一", "（附則第二条関係）"))
+      ),
+      (None, Some(appdx("別表第一", "（第一条関係）"))),
+    ]
+  );
+  assert!(matches!(&lst[0].contents, AppdxContents::Table(t) if t.len() == 1));
+  assert!(lst[0].article_index.is_suppl_provision());
+  assert!(lst[1].article_index.is_appdx());
+  assert_eq!(AppdxKind::Table.to_string(), "別表");
+
+  let entries = article_entry_list_from_lawbody("505AC0000000001", "テスト法", &law.law_body);
+  let index_list = entries
+    .iter()
+    .map(|e| e.article_index.clone())
+    .collect::<Vec<_>>();
+  assert_eq!(index_list.len(), 4);
+  assert!(!index_list[0].is_appdx());
+  assert_eq!(index_list[2], lst[0].article_index);
+  assert_eq!(index_list[3], lst[1].article_index);
+  let mut sorted = index_list.clone();
+  sorted.reverse();
+  sorted.sort();
+  assert_eq!(sorted, index_list);
+  assert_eq!(entries[3].title.as_deref(), Some("別表第一"));
+  assert_eq!(entries[3].paragraphs[0].struct_list.len(), 1);
+}
+
+#[test]
+fn check_appdx_to_paragraph() {
+  let law = test_law(
+    r#"<MainProvision>
+  <Paragraph Num="1">
+    <ParagraphNum/>
+    <ParagraphSentence><Sentence Num="1">この法律は、公布の日から施行する。</Sentence></ParagraphSentence>
+  </Paragraph>
+</MainProvision>
+<AppdxTable Num="1">
+  <AppdxTableTitle>別表第一</AppdxTableTitle>
+  <TableStruct>
+    <Table>
+      <TableRow><TableColumn><Sentence Num="1">甲</Sentence></TableColumn></TableRow>
+    </Table>
+  </TableStruct>
+  <Remarks>
+    <RemarksLabel>備考</RemarksLabel>
+    <Sentence Num="1">この表の甲は乙とする。</Sentence>
+  </Remarks>
+</AppdxTable>
+<AppdxNote>
+  <AppdxNoteTitle>別記</AppdxNoteTitle>
+  <NoteStruct>
+    <Note><Sentence Num="1">証明書</Sentence>氏名</Note>
+  </NoteStruct>
+</AppdxNote>
+<AppdxFormat>
+  <AppdxFormatTitle>別記書式</AppdxFormatTitle>
+  <FormatStruct>
+    <Format><Sentence Num="1">申請書</Sentence></Format>
+  </FormatStruct>
+</AppdxFormat>"#,
+  );
+  let entries = article_entry_list_from_lawbody("505AC0000000001", "テスト法", &law.law_body);
+  let sentences = |entry: &ArticleEntry| {
+    entry.paragraphs[0]
+      .sentence
+      .iter()
+      .map(|t| sentence_element_to_str(&t.contents))
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(entries.len(), 4);
+  assert_eq!(entries[1].paragraphs[0].struct_list.len(), 1);
+  assert_eq!(sentences(&entries[1]), vec!["この表の甲は乙とする。"]);
+  assert_eq!(sentences(&entries[2]), vec!["証明書", "氏名"]);
+  assert!(sentences(&entries[3]).is_empty());
+}
+
+/// 条文内でのテキストの位置を示す
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextIndex {
+  /// 段落番号
+  pub paragraph: ArticleNumber,
+  /// 号の番号を上の階層から並べる
+  /// 何もないときは空
+  pub items: Vec<Option<ArticleNumber>>,
+  /// 欄ごとに分けて取り出したときの欄の番号
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub column: Option<usize>,
+  /// 表の中のテキストのときのセルの位置
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub table_cell: Option<TableCellIndex>,
+  /// 文ごとに分けて取り出したときの文の`Num`属性
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sentence_num: Option<usize>,
+  /// 文ごとに分けて取り出したときの文の`Function`属性
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sentence_function: Option<SentenceFunction>,
+}
+
+impl TextIndex {
+  /// 文ごとに分けて取り出したときに、「本文」「ただし書」「前段」「後段」のような文の呼び方を返す
+  /// `sentence_count`には同じ段落や号の中の文の数を指定する
+  /// 文が一つしかない場合や四つ以上ある場合は`None`になる
+  pub fn sentence_part_name(&self, sentence_count: usize) -> Option<&'static str> {
+    match (&self.sentence_function, self.sentence_num, sentence_count) {
+      (Some(SentenceFunction::Main), _, _) => Some("本文"),
+      (Some(SentenceFunction::Proviso), _, _) => Some("ただし書"),
+      (None, Some(1), 2 | 3) => Some("前段"),
+      (None, Some(2), 3) => Some("中段"),
+      (None, Some(n), 2 | 3) if n == sentence_count => Some("後段"),
+      _ => None,
+    }
+  }
+}
+
+/// 表の中のセルの位置
+/// 行・列ともに1から数え、行はヘッダー行を含めて上から数える
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TableCellIndex {
+  /// 行
+  pub row: usize,
+  /// 列
+  pub column: usize,
+}
+
+/// 段落からテキストを取り出すときの設定
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextListOptions {
+  /// 「語　意味」のような欄をつなげるときの区切り文字
+  pub column_separator: String,
+  /// 欄をつなげずに欄ごとに取り出すかどうか
+  /// 表のセルの中の欄は常につなげる
+  pub split_columns: bool,
+  /// 段落や号の文をつなげずに文ごとに取り出すかどうか
+  /// 表のセルの中の文は常につなげる
+  pub split_sentences: bool,
+  /// 文の出力方法
+  pub render: TextRenderOptions,
+  /// 取り出した文字列の表記をそろえる設定
+  /// [`text_list_with_source_map`]では文字列の範囲が変わってしまうため使わない
+  pub normalize: Option<NormalizeOptions>,
+}
+
+impl Default for TextListOptions {
+  fn default() -> Self {
+    Self {
+      column_separator: "　".to_string(),
+      split_columns: false,
+      split_sentences: false,
+      render: TextRenderOptions::default(),
+      normalize: None,
+    }
+  }
+}
+
+/// 文の中の要素の種類
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SentenceElementKind {
+  /// 傍線
+  Line,
+  /// 引用
+  QuoteStruct,
+  /// 数式
+  ArithFormula,
+  /// ルビ
+  Ruby,
+  /// 上付き文字
+  Sup,
+  /// 下付き文字
+  Sub,
+  /// 文字列
+  String,
+}
+
+impl From<&SentenceElement> for SentenceElementKind {
+  fn from(element: &SentenceElement) -> Self {
+    match element {
+      SentenceElement::Line(_) => SentenceElementKind::Line,
+      SentenceElement::QuoteStruct(_) => SentenceElementKind::QuoteStruct,
+      SentenceElement::ArithFormula(_) => SentenceElementKind::ArithFormula,
+      SentenceElement::Ruby(_) => SentenceElementKind::Ruby,
+      SentenceElement::Sup(_) => SentenceElementKind::Sup,
+      SentenceElement::Sub(_) => SentenceElementKind::Sub,
+      SentenceElement::String(_) => SentenceElementKind::String,
+    }
+  }
+}
+
+/// 出力した文字列の範囲と、その元になった文の要素の対応
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceSpan {
+  /// 出力した文字列でのバイト範囲
+  pub range: Range<usize>,
+  /// 段落や号の中で何番目の文か（0から数える）
+  pub sentence_index: usize,
+  /// 文の`Num`属性
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sentence_num: Option<usize>,
+  /// 文の`Function`属性（本文かただし書か）
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sentence_function: Option<SentenceFunction>,
+  /// 欄の中の文の場合は欄の番号
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub column: Option<usize>,
+  /// 文の中で何番目の要素か（0から数える）
+  pub element_index: usize,
+  /// 要素の種類
+  pub element_kind: SentenceElementKind,
+  /// 範囲の開始位置が、要素を出力した文字列の先頭から何バイト目か
+  pub element_offset: usize,
+  /// 出力が元の文字列をそのまま写したものかどうか
+  /// `true`のときは`element_offset`が元の文字列でのバイト位置になる
+  pub verbatim: bool,
+}
+
+impl SourceSpan {
+  /// 元の文字列でのバイト位置
+  /// 出力が元の文字列と異なる場合は`None`になる
+  pub fn original_offset(&self) -> Option<usize> {
+    self.verbatim.then_some(self.element_offset)
+  }
+}
+
+/// 出力した文字列の範囲と元の文の要素の対応表
+/// 表のセルの中の段落など、文の直下にない要素から出力した部分は含まれない
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSourceMap {
+  /// 出力した文字列での位置の順に並んだ対応
+  pub spans: Vec<SourceSpan>,
+}
+
+impl TextSourceMap {
+  fn append_shifted(&mut self, other: TextSourceMap, offset: usize) {
+    self.spans.extend(other.spans.into_iter().map(|mut span| {
+      span.range = span.range.start + offset..span.range.end + offset;
+      span
+    }));
+  }
+
+  /// 出力した文字列でのバイト位置を含む要素を探す
+  pub fn locate(&self, offset: usize) -> Option<&SourceSpan> {
+    self.spans.iter().find(|span| span.range.contains(&offset))
+  }
+
+  /// 出力した文字列でのバイト範囲に対応する要素を、範囲に合わせて切り詰めて返す
+  pub fn span(&self, range: Range<usize>) -> TextSpan {
+    let sources = self
+      .spans
+      .iter()
+      .filter(|span| span.range.start < range.end && range.start < span.range.end)
+      .map(|span| {
+        let start = span.range.start.max(range.start);
+        let end = span.range.end.min(range.end);
+        SourceSpan {
+          range: start..end,
+          element_offset: span.element_offset + start - span.range.start,
+          ..span.clone()
+        }
+      })
+      .collect();
+    TextSpan { range, sources }
+  }
+}
+
+/// 解析結果が出力した文字列のどこにあるかを示す
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSpan {
+  /// 出力した文字列でのバイト範囲
+  pub range: Range<usize>,
+  /// 範囲に含まれる元の文の要素
+  pub sources: Vec<SourceSpan>,
+}
+
+/// 段落のリストから文字列のリストとそのインデックスの組を生成する
+/// ルビと線は無視し、上付き文字は`^`、下付き文字は`_`で出力する
+/// 欄は全角空白でつなげ、表はセルごとに出力する
+pub fn text_list_from_paragraph(lst: &[Paragraph]) -> Vec<(TextIndex, String)> {
+  text_list_from_paragraph_with(lst, &TextListOptions::default())
+}
+
+/// 段落のリストから、設定に従って文字列のリストとそのインデックスの組を生成する
+pub fn text_list_from_paragraph_with(
+  lst: &[Paragraph],
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String)> {
+  text_list_with_source_map(lst, options)
+    .into_iter()
+    .map(|(index, text, _)| (index, normalize_with(text, options)))
+    .collect()
+}
+
+fn normalize_with(text: String, options: &TextListOptions) -> String {
+  match &options.normalize {
+    Some(normalize_options) => normalize(&text, normalize_options),
+    None => text,
+  }
+}
+
+/// 段落のリストから、設定に従って文字列のリストとそのインデックスに加えて、文字列の範囲と元の文の要素との対応を生成する
+pub fn text_list_with_source_map(
+  lst: &[Paragraph],
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
+  let mut v = Vec::new();
+  for para in lst.iter() {
+    let paragraph_num = &para.num;
+    let index = TextIndex {
+      paragraph: paragraph_num.clone(),
+      items: Vec::new(),
+      column: None,
+      table_cell: None,
+      sentence_num: None,
+      sentence_function: None,
+    };
+    v.append(&mut text_list_from_sentence_list(
+      index,
+      &para.sentence,
+      options,
+      None,
+    ));
+    let mut v2 = text_list_from_item(paragraph_num, Vec::new(), &para.children, options);
+    v.append(&mut v2);
+  }
+  v
+}
+
+fn text_list_from_item(
+  para_num: &ArticleNumber,
+  items: Vec<Option<ArticleNumber>>,
+  chldren: &[Item],
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
+  let mut v = Vec::new();
+  for t in chldren.iter() {
+    let mut l = items.clone();
+    l.push(t.num.clone());
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
+    ));
+    let mut v2 = text_list_from_subitem1(para_num, l, &t.children, options);
+    v.append(&mut v2);
+  }
+  v
+}
+
+/// 号の文・欄・表からテキストを取り出す
+fn text_list_from_sentence(
+  para_num: &ArticleNumber,
+  items: &[Option<ArticleNumber>],
+  sentence: &SentenceOrColumnOrTable,
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
+  let index = |column, table_cell| TextIndex {
+    paragraph: para_num.clone(),
+    items: items.to_vec(),
+    column,
+    table_cell,
+    sentence_num: None,
+    sentence_function: None,
+  };
+  match sentence {
+    SentenceOrColumnOrTable::Sentence(se) => {
+      text_list_from_sentence_list(index(None, None), se, options, None)
+    }
+    SentenceOrColumnOrTable::Column(columns) if options.split_columns => columns
+      .iter()
+      .enumerate()
+      .flat_map(|(i, c)| {
+        let column = c.num.unwrap_or(i + 1);
+        text_list_from_sentence_list(
+          index(Some(column), None),
+          &c.sentence,
+          options,
+          Some(column),
+        )
+      })
+      .collect(),
+    SentenceOrColumnOrTable::Column(columns) => {
+      let (text, source_map) = column_list_to_str_with_map(columns, options);
+      vec![(index(None, None), text, source_map)]
     }
+    SentenceOrColumnOrTable::Table(table) => table_to_text_list_with_map(table, options)
+      .into_iter()
+      .map(|(cell, text, source_map)| (index(None, Some(cell)), text, source_map))
+      .collect(),
   }
-  v
 }
 
-#[allow(clippy::too_many_arguments)]
-fn article_list_from_subsection(
-  file_id: &str,
-  law_name: &str,
-  part_number: Option<&ArticleNumber>,
-  chapter_number: Option<&ArticleNumber>,
-  seciton_number: Option<&ArticleNumber>,
-  subseciton_number: Option<&ArticleNumber>,
-  suppl_provision_name: Option<&String>,
-  lst: &[SubsectionContents],
-) -> Vec<AnalysisResultInfo<Vec<Paragraph>>> {
-  let mut v = Vec::new();
-  for contents in lst.iter() {
-    match contents {
-      SubsectionContents::Division(t) => {
-        let mut v2 = article_list_from_division(
-          file_id,
-          law_name,
-          part_number,
-          chapter_number,
-          seciton_number,
-          None,
-          &t.num,
-          suppl_provision_name,
-          &t.children,
-        );
-        v.append(&mut v2)
-      }
-      SubsectionContents::Article(t) => {
-        let article_index = ArticleIndex {
-          file_id: file_id.to_string(),
-          law_name: law_name.to_string(),
-          article_number: t.num.clone(),
-          part_number: part_number.cloned(),
-          chapter_number: chapter_number.cloned(),
-          section_number: seciton_number.cloned(),
-          subsection_number: subseciton_number.cloned(),
-          division_number: subseciton_number.cloned(),
-          suppl_provision_name: None,
+/// 文のリストを、設定に従って文ごとに分けるかまとめて文字列にする
+fn text_list_from_sentence_list(
+  index: TextIndex,
+  lst: &[Sentence],
+  options: &TextListOptions,
+  column: Option<usize>,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
+  if options.split_sentences {
+    lst
+      .iter()
+      .enumerate()
+      .map(|(i, sentence)| {
+        let (text, mut source_map) =
+          sentence_list_to_str_with_map(std::slice::from_ref(sentence), &options.render, column);
+        for span in source_map.spans.iter_mut() {
+          span.sentence_index = i;
+        }
+        let index = TextIndex {
+          sentence_num: sentence.num,
+          sentence_function: sentence.function.clone(),
+          ..index.clone()
         };
-        v.push(AnalysisResultInfo {
-          article_index,
-          text_index_opt: None,
-          result: t.paragraph.clone(),
-        })
+        (index, text, source_map)
+      })
+      .collect()
+  } else {
+    let (text, source_map) = sentence_list_to_str_with_map(lst, &options.render, column);
+    vec![(index, text, source_map)]
+  }
+}
+
+/// 文のリストを要素ごとに文字列にし、文字列の範囲と元の要素の対応を記録する
+fn sentence_list_to_str_with_map(
+  lst: &[Sentence],
+  options: &TextRenderOptions,
+  column: Option<usize>,
+) -> (String, TextSourceMap) {
+  let mut s = String::new();
+  let mut source_map = TextSourceMap::default();
+  for (sentence_index, sentence) in lst.iter().enumerate() {
+    for (element_index, element) in sentence.contents.iter().enumerate() {
+      let text = sentence_element_to_string_with(std::slice::from_ref(element), options);
+      if text.is_empty() {
+        continue;
       }
+      let element_kind = SentenceElementKind::from(element);
+      let verbatim = match element_kind {
+        SentenceElementKind::String => true,
+        SentenceElementKind::Sup | SentenceElementKind::Sub => options.script == ScriptStyle::Plain,
+        _ => false,
+      };
+      source_map.spans.push(SourceSpan {
+        range: s.len()..s.len() + text.len(),
+        sentence_index,
+        sentence_num: sentence.num,
+        sentence_function: sentence.function.clone(),
+        column,
+        element_index,
+        element_kind,
+        element_offset: 0,
+        verbatim,
+      });
+      s.push_str(&text);
     }
   }
-  v
+  (s, source_map)
 }
 
-#[allow(clippy::too_many_arguments)]
-fn article_list_from_division(
-  file_id: &str,
-  law_name: &str,
-  part_number: Option<&ArticleNumber>,
-  chapter_number: Option<&ArticleNumber>,
-  seciton_number: Option<&ArticleNumber>,
-  subseciton_number: Option<&ArticleNumber>,
-  division_number: &ArticleNumber,
-  suppl_provision_name: Option<&String>,
-  lst: &[Article],
-) -> Vec<AnalysisResultInfo<Vec<Paragraph>>> {
-  let mut v = Vec::new();
-  for t in lst.iter() {
-    let article_index = ArticleIndex {
-      file_id: file_id.to_string(),
-      law_name: law_name.to_string(),
-      article_number: t.num.clone(),
-      part_number: part_number.cloned(),
-      chapter_number: chapter_number.cloned(),
-      section_number: seciton_number.cloned(),
-      subsection_number: subseciton_number.cloned(),
-      division_number: Some(division_number.clone()),
-      suppl_provision_name: suppl_provision_name.cloned(),
-    };
-    v.push(AnalysisResultInfo {
-      article_index,
-      text_index_opt: None,
-      result: t.paragraph.clone(),
-    })
+fn column_list_to_str(lst: &[Column], options: &TextListOptions) -> String {
+  column_list_to_str_with_map(lst, options).0
+}
+
+fn column_list_to_str_with_map(
+  lst: &[Column],
+  options: &TextListOptions,
+) -> (String, TextSourceMap) {
+  let mut s = String::new();
+  let mut source_map = TextSourceMap::default();
+  for (i, c) in lst.iter().enumerate() {
+    if i != 0 {
+      s.push_str(&options.column_separator);
+    }
+    let (text, map) =
+      sentence_list_to_str_with_map(&c.sentence, &options.render, Some(c.num.unwrap_or(i + 1)));
+    source_map.append_shifted(map, s.len());
+    s.push_str(&text);
   }
-  v
+  (s, source_map)
 }
 
-/// 条文内でのテキストの位置を示す
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct TextIndex {
-  /// 段落番号
-  pub paragraph: ArticleNumber,
-  /// 号の番号を上の階層から並べる
-  /// 何もないときは空
-  pub items: Vec<Option<ArticleNumber>>,
+/// 表をセルごとのテキストに分ける
+/// ヘッダー行も1行として数える
+pub fn table_to_text_list(
+  table: &Table,
+  options: &TextListOptions,
+) -> Vec<(TableCellIndex, String)> {
+  table_to_text_list_with_map(table, options)
+    .into_iter()
+    .map(|(cell, text, _)| (cell, normalize_with(text, options)))
+    .collect()
 }
 
-/// 段落のリストから文字列のリストとそのインデックスの組を生成する
-/// ルビと線は無視し、上付き文字は`^`、下付き文字は`_`で出力する
-pub fn text_list_from_paragraph(lst: &[Paragraph]) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for para in lst.iter() {
-    let paragraph_num = &para.num;
-    let sentence_text = para
-      .sentence
+fn table_to_text_list_with_map(
+  table: &Table,
+  options: &TextListOptions,
+) -> Vec<(TableCellIndex, String, TextSourceMap)> {
+  let header = table.table_header_row.iter().map(|row| {
+    row
+      .columns
       .iter()
-      .map(|sentence| sentence_element_to_str(&sentence.contents))
-      .collect::<String>();
-    v.push((
-      TextIndex {
-        paragraph: paragraph_num.clone(),
-        items: Vec::new(),
-      },
-      sentence_text,
-    ));
-    let mut items = Vec::new();
-    for item in para.children.iter() {
-      let n = &item.num;
-      items.push(n.clone());
-      // childrenとsentenceの処理をする
-      let sentence_str = match &item.sentence {
-        SentenceOrColumnOrTable::Sentence(se) => se
-          .iter()
-          .map(|sentence| sentence_element_to_str(&sentence.contents))
-          .collect::<String>(),
-        _ => String::new(),
-      };
-      v.push((
-        TextIndex {
-          paragraph: paragraph_num.clone(),
-          items: items.clone(),
-        },
-        sentence_str,
-      ));
-      let mut v2 = text_list_from_subitem1(paragraph_num, items.clone(), &item.children);
-      v.append(&mut v2);
-      items.pop();
+      .map(|t| {
+        (
+          text_to_string_with(t, &options.render),
+          TextSourceMap::default(),
+        )
+      })
+      .collect::<Vec<_>>()
+  });
+  let body = table.table_row.iter().map(|row| {
+    row
+      .columns
+      .iter()
+      .map(|c| table_column_to_str_with_map(c, options))
+      .collect::<Vec<_>>()
+  });
+  header
+    .chain(body)
+    .enumerate()
+    .flat_map(|(row, cells)| {
+      cells
+        .into_iter()
+        .enumerate()
+        .map(move |(column, (text, source_map))| {
+          (
+            TableCellIndex {
+              row: row + 1,
+              column: column + 1,
+            },
+            text,
+            source_map,
+          )
+        })
+    })
+    .collect()
+}
+
+fn table_column_to_str(column: &TableColumn, options: &TextListOptions) -> String {
+  table_column_to_str_with_map(column, options).0
+}
+
+/// セルの中身を一つのテキストにする
+/// セルの直下の文と欄のみ元の要素との対応を記録する
+/// 図は無視する
+fn table_column_to_str_with_map(
+  column: &TableColumn,
+  options: &TextListOptions,
+) -> (String, TextSourceMap) {
+  let zero = ArticleNumber::zero();
+  let join = |lst: Vec<(TextIndex, String, TextSourceMap)>| {
+    lst.into_iter().map(|(_, s, _)| s).collect::<String>()
+  };
+  let articles = |lst: Vec<ArticleEntry>| {
+    lst
+      .iter()
+      .map(|entry| join(text_list_with_source_map(&entry.paragraphs, options)))
+      .collect::<String>()
+  };
+  let ctx = StructureContext::new("", "", None);
+  let mut s = String::new();
+  let mut source_map = TextSourceMap::default();
+  for contents in column.contents.iter() {
+    match contents {
+      TableColumnContents::String(t) => s.push_str(t),
+      TableColumnContents::Sentence(t) => {
+        let (text, map) =
+          sentence_list_to_str_with_map(std::slice::from_ref(t), &options.render, None);
+        source_map.append_shifted(map, s.len());
+        s.push_str(&text);
+      }
+      TableColumnContents::Column(t) => {
+        let (text, map) = column_list_to_str_with_map(std::slice::from_ref(t), options);
+        source_map.append_shifted(map, s.len());
+        s.push_str(&text);
+      }
+      TableColumnContents::Part(t) => {
+        let mut v = Vec::new();
+        article_entry_list_from_part(&ctx, &t.children, &mut v);
+        s.push_str(&articles(v));
+      }
+      TableColumnContents::Chapter(t) => {
+        let mut v = Vec::new();
+        article_entry_list_from_chapter(&ctx, &t.children, &mut v);
+        s.push_str(&articles(v));
+      }
+      TableColumnContents::Section(t) => {
+        let mut v = Vec::new();
+        article_entry_list_from_section(&ctx, &t.children, &mut v);
+        s.push_str(&articles(v));
+      }
+      TableColumnContents::Subsection(t) => {
+        let mut v = Vec::new();
+        article_entry_list_from_subsection(&ctx, &t.children, &mut v);
+        s.push_str(&articles(v));
+      }
+      TableColumnContents::Division(t) => {
+        s.push_str(&articles(
+          t.children.iter().map(|t| ctx.article(t)).collect(),
+        ));
+      }
+      TableColumnContents::Article(t) => {
+        s.push_str(&join(text_list_with_source_map(&t.paragraph, options)))
+      }
+      TableColumnContents::Paragraph(t) => s.push_str(&join(text_list_with_source_map(
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Item(t) => s.push_str(&join(text_list_from_item(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem1(t) => s.push_str(&join(text_list_from_subitem1(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem2(t) => s.push_str(&join(text_list_from_subitem2(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem3(t) => s.push_str(&join(text_list_from_subitem3(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem4(t) => s.push_str(&join(text_list_from_subitem4(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem5(t) => s.push_str(&join(text_list_from_subitem5(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem6(t) => s.push_str(&join(text_list_from_subitem6(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem7(t) => s.push_str(&join(text_list_from_subitem7(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem8(t) => s.push_str(&join(text_list_from_subitem8(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem9(t) => s.push_str(&join(text_list_from_subitem9(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Subitem10(t) => s.push_str(&join(text_list_from_subitem10(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        options,
+      ))),
+      TableColumnContents::Remarks(t) => s.push_str(&remarks_to_str(t, options)),
+      TableColumnContents::FigStruct(_) => (),
     }
   }
-  v
+  (s, source_map)
+}
+
+fn remarks_to_str(remarks: &Remarks, options: &TextListOptions) -> String {
+  let mut s = String::new();
+  for r in remarks.children.iter() {
+    match r {
+      RemarksContents::Sentence(t) => s.push_str(&sentence_element_to_string_with(
+        &t.contents,
+        &options.render,
+      )),
+      RemarksContents::Item(t) => {
+        for (_, text, _) in text_list_from_item(
+          &ArticleNumber::zero(),
+          Vec::new(),
+          std::slice::from_ref(t),
+          options,
+        ) {
+          s.push_str(&text);
+        }
+      }
+    }
+  }
+  s
 }
 
 fn text_list_from_subitem1(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem1],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem2(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem2(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -500,26 +1811,19 @@ fn text_list_from_subitem2(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem2],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem3(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem3(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -529,26 +1833,19 @@ fn text_list_from_subitem3(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem3],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem4(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem4(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -558,26 +1855,19 @@ fn text_list_from_subitem4(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem4],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem5(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem5(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -587,26 +1877,19 @@ fn text_list_from_subitem5(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem5],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem6(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem6(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -616,26 +1899,19 @@ fn text_list_from_subitem6(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem6],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem7(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem7(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -645,26 +1921,19 @@ fn text_list_from_subitem7(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem7],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem8(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem8(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -674,26 +1943,19 @@ fn text_list_from_subitem8(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem8],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem9(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem9(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -703,26 +1965,19 @@ fn text_list_from_subitem9(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem9],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
-    let mut v2 = text_list_from_subitem10(para_num, l.clone(), &t.children);
+    let mut v2 = text_list_from_subitem10(para_num, l, &t.children, options);
     v.append(&mut v2);
   }
   v
@@ -732,67 +1987,416 @@ fn text_list_from_subitem10(
   para_num: &ArticleNumber,
   items: Vec<Option<ArticleNumber>>,
   chldren: &[Subitem10],
-) -> Vec<(TextIndex, String)> {
+  options: &TextListOptions,
+) -> Vec<(TextIndex, String, TextSourceMap)> {
   let mut v = Vec::new();
   for t in chldren.iter() {
     let mut l = items.clone();
     l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      _ => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l,
-      },
-      sentence_str,
+    v.append(&mut text_list_from_sentence(
+      para_num,
+      &l,
+      &t.sentence,
+      options,
     ));
   }
   v
 }
 
+/// ルビの出力方法
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RubyStyle {
+  /// ルビの付いた本文ごと出力しない
+  Omit,
+  /// 本文のみを出力する
+  Base,
+  /// 「漢字（かんじ）」のように本文の後に読みを出力する
+  BaseWithReading,
+}
+
+/// 上付き文字・下付き文字の出力方法
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScriptStyle {
+  /// 出力しない
+  Omit,
+  /// そのまま出力する
+  Plain,
+  /// `^{2}`・`_{1}`のように出力する
+  Latex,
+}
+
+/// 数式の出力方法
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormulaStyle {
+  /// 出力しない
+  Omit,
+  /// 文字列と上付き文字・下付き文字などの文中の要素のみを出力する
+  Text,
+  /// 数式中の文や表なども含めて出力する
+  Full,
+}
+
+/// 文を文字列にするときの設定
+/// `Default`は[`sentence_element_to_str`]と同じ出力になる
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextRenderOptions {
+  /// ルビ
+  pub ruby: RubyStyle,
+  /// 上付き文字・下付き文字
+  pub script: ScriptStyle,
+  /// 傍線の付いた部分を出力するかどうか
+  pub line: bool,
+  /// 改正文などの引用部分を出力するかどうか
+  pub quote_struct: bool,
+  /// 数式
+  pub arith_formula: FormulaStyle,
+}
+
+impl Default for TextRenderOptions {
+  fn default() -> Self {
+    Self {
+      ruby: RubyStyle::Omit,
+      script: ScriptStyle::Latex,
+      line: false,
+      quote_struct: false,
+      arith_formula: FormulaStyle::Text,
+    }
+  }
+}
+
+impl TextRenderOptions {
+  /// 全文検索などのために、書かれているテキストを全てそのまま出力する設定
+  pub fn plain() -> Self {
+    Self {
+      ruby: RubyStyle::Base,
+      script: ScriptStyle::Plain,
+      line: true,
+      quote_struct: true,
+      arith_formula: FormulaStyle::Full,
+    }
+  }
+
+  /// 数式を`^{2}`のような記法で出力し、それ以外は[`TextRenderOptions::plain`]と同じにする設定
+  pub fn latex() -> Self {
+    Self {
+      script: ScriptStyle::Latex,
+      ..Self::plain()
+    }
+  }
+}
+
+/// 文の中身を文字列にする
+/// ルビと線と引用部分は無視し、上付き文字は`^`、下付き文字は`_`で出力する
 pub fn sentence_element_to_str(element: &[SentenceElement]) -> String {
+  sentence_element_to_string_with(element, &TextRenderOptions::default())
+}
+
+/// 文の中身を設定に従って文字列にする
+pub fn sentence_element_to_string_with(
+  element: &[SentenceElement],
+  options: &TextRenderOptions,
+) -> String {
   let mut s = String::new();
   for e in element.iter() {
     match e {
       SentenceElement::String(s2) => s.push_str(s2),
-      SentenceElement::Sub(s2) => {
-        s.push_str("_{");
-        s.push_str(&s2.text);
-        s.push('}');
+      SentenceElement::Sub(s2) => push_script(&mut s, "_", &s2.text, options),
+      SentenceElement::Sup(s2) => push_script(&mut s, "^", &s2.text, options),
+      SentenceElement::Ruby(ruby) => push_ruby(&mut s, ruby, options),
+      SentenceElement::Line(line) => push_line(&mut s, line, options),
+      SentenceElement::QuoteStruct(quote) => push_quote_struct(&mut s, quote, options),
+      SentenceElement::ArithFormula(arith_formula) => {
+        push_arith_formula(&mut s, arith_formula, options)
+      }
+    }
+  }
+  s
+}
+
+/// ルビや上付き文字などを含むテキストを設定に従って文字列にする
+pub fn text_to_string_with(text: &Text, options: &TextRenderOptions) -> String {
+  let mut s = String::new();
+  for e in text.contents.iter() {
+    match e {
+      TextElement::Text(s2) => s.push_str(s2),
+      TextElement::Sub(s2) => push_script(&mut s, "_", &s2.text, options),
+      TextElement::Sup(s2) => push_script(&mut s, "^", &s2.text, options),
+      TextElement::Ruby(ruby) => push_ruby(&mut s, ruby, options),
+      TextElement::Line(line) => push_line(&mut s, line, options),
+    }
+  }
+  s
+}
+
+fn push_script(s: &mut String, mark: &str, text: &str, options: &TextRenderOptions) {
+  match options.script {
+    ScriptStyle::Omit => (),
+    ScriptStyle::Plain => s.push_str(text),
+    ScriptStyle::Latex => {
+      s.push_str(mark);
+      s.push('{');
+      s.push_str(text);
+      s.push('}');
+    }
+  }
+}
+
+fn push_ruby(s: &mut String, ruby: &Ruby, options: &TextRenderOptions) {
+  match options.ruby {
+    RubyStyle::Omit => (),
+    RubyStyle::Base => s.push_str(&text_to_string_with(&ruby.text, options)),
+    RubyStyle::BaseWithReading => {
+      s.push_str(&text_to_string_with(&ruby.text, options));
+      s.push('（');
+      s.push_str(&ruby.ruby);
+      s.push('）');
+    }
+  }
+}
+
+fn push_line(s: &mut String, line: &Line, options: &TextRenderOptions) {
+  if !options.line {
+    return;
+  }
+  for e in line.contents.iter() {
+    match e {
+      LineContents::String(s2) => s.push_str(s2),
+      LineContents::Sub(s2) => push_script(s, "_", &s2.text, options),
+      LineContents::Sup(s2) => push_script(s, "^", &s2.text, options),
+      LineContents::Ruby(ruby) => push_ruby(s, ruby, options),
+      LineContents::QuoteStruct(quote) => push_quote_struct(s, quote, options),
+      LineContents::ArithFormula(arith_formula) => push_arith_formula(s, arith_formula, options),
+    }
+  }
+}
+
+fn push_quote_struct(s: &mut String, quote: &QuoteStruct, options: &TextRenderOptions) {
+  if options.quote_struct {
+    push_contents(s, &quote.contentes.contents, options, true);
+  }
+}
+
+fn push_arith_formula(s: &mut String, arith_formula: &ArithFormula, options: &TextRenderOptions) {
+  match options.arith_formula {
+    FormulaStyle::Omit => (),
+    FormulaStyle::Text => push_contents(s, &arith_formula.contentes.contents, options, false),
+    FormulaStyle::Full => push_contents(s, &arith_formula.contentes.contents, options, true),
+  }
+}
+
+/// 引用部分や数式の中身を文字列にする
+/// `block`が`false`のときは文中に現れる要素のみを出力する
+/// 図・記・様式は出力しない
+fn push_contents(
+  s: &mut String,
+  lst: &[ContentsElement],
+  options: &TextRenderOptions,
+  block: bool,
+) {
+  let list_options = TextListOptions {
+    render: options.clone(),
+    ..Default::default()
+  };
+  let zero = ArticleNumber::zero();
+  let join = |lst: Vec<(TextIndex, String, TextSourceMap)>| {
+    lst.into_iter().map(|(_, s, _)| s).collect::<String>()
+  };
+  for c in lst.iter() {
+    match c {
+      ContentsElement::String(s2) => s.push_str(s2),
+      ContentsElement::Sub(s2) => push_script(s, "_", &s2.text, options),
+      ContentsElement::Sup(s2) => push_script(s, "^", &s2.text, options),
+      ContentsElement::Ruby(ruby) => push_ruby(s, ruby, options),
+      ContentsElement::Line(line) => push_line(s, line, options),
+      ContentsElement::QuoteStruct(quote) => push_quote_struct(s, quote, options),
+      ContentsElement::ArithFormula(arith_formula) => push_arith_formula(s, arith_formula, options),
+      _ if !block => (),
+      ContentsElement::Sentence(t) => {
+        s.push_str(&sentence_element_to_string_with(&t.contents, options))
+      }
+      ContentsElement::Paragraph(t) => s.push_str(&join(text_list_with_source_map(
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Item(t) => s.push_str(&join(text_list_from_item(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem1(t) => s.push_str(&join(text_list_from_subitem1(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem2(t) => s.push_str(&join(text_list_from_subitem2(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem3(t) => s.push_str(&join(text_list_from_subitem3(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem4(t) => s.push_str(&join(text_list_from_subitem4(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem5(t) => s.push_str(&join(text_list_from_subitem5(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem6(t) => s.push_str(&join(text_list_from_subitem6(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem7(t) => s.push_str(&join(text_list_from_subitem7(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem8(t) => s.push_str(&join(text_list_from_subitem8(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem9(t) => s.push_str(&join(text_list_from_subitem9(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Subitem10(t) => s.push_str(&join(text_list_from_subitem10(
+        &zero,
+        Vec::new(),
+        std::slice::from_ref(t),
+        &list_options,
+      ))),
+      ContentsElement::Table(t) => push_table(s, t, &list_options),
+      ContentsElement::TableStruct(t) => {
+        if let Some(title) = &t.title {
+          s.push_str(&title.to_string());
+        }
+        push_table(s, &t.table, &list_options);
       }
-      SentenceElement::Sup(s2) => {
-        s.push_str("^{");
-        s.push_str(&s2.text);
-        s.push('}');
+      ContentsElement::TableRow(t) => {
+        for c in t.columns.iter() {
+          s.push_str(&table_column_to_str(c, &list_options));
+        }
       }
-      SentenceElement::ArithFormula(arith_formula) => {
-        let contents = &arith_formula.contentes.contents;
-        for c in contents.iter() {
+      ContentsElement::AppdxTable(t) => {
+        if let Some(title) = &t.title {
+          s.push_str(&title.to_string());
+        }
+        for c in t.children.iter() {
           match c {
-            ContentsElement::String(s2) => s.push_str(s2),
-            ContentsElement::Sub(s2) => {
-              s.push_str("_{");
-              s.push_str(&s2.text);
-              s.push('}');
-            }
-            ContentsElement::Sup(s2) => {
-              s.push_str("^{");
-              s.push_str(&s2.text);
-              s.push('}');
-            }
-            _ => (),
+            AppdxTableContents::TableStruct(t) => push_table(s, &t.table, &list_options),
+            AppdxTableContents::Item(t) => s.push_str(&join(text_list_from_item(
+              &zero,
+              Vec::new(),
+              std::slice::from_ref(t),
+              &list_options,
+            ))),
           }
         }
       }
+      ContentsElement::List(t) => push_list(s, t, &list_options),
+      ContentsElement::Remarks(t) => s.push_str(&remarks_to_str(t, &list_options)),
+      ContentsElement::TOCSection(t) => {
+        s.push_str(&text_to_string_with(&t.section_title, options));
+        if let Some(range) = &t.article_range {
+          s.push_str(&text_to_string_with(range, options));
+        }
+      }
+      // 図・記・様式と目次は出力しない
       _ => (),
     }
   }
-  s
+}
+
+fn push_table(s: &mut String, table: &Table, options: &TextListOptions) {
+  for (_, text) in table_to_text_list(table, options) {
+    s.push_str(&text);
+  }
+}
+
+fn push_list(s: &mut String, list: &List, options: &TextListOptions) {
+  let sentence = |lst: &[ListSentence]| {
+    lst
+      .iter()
+      .map(|t| match t {
+        ListSentence::Sentence(t) => sentence_element_to_string_with(&t.contents, &options.render),
+        ListSentence::Column(t) => column_list_to_str(std::slice::from_ref(t), options),
+      })
+      .collect::<String>()
+  };
+  s.push_str(&sentence(&list.sentence));
+  for t1 in list.children.iter() {
+    s.push_str(&sentence(&t1.sentence));
+    for t2 in t1.children.iter() {
+      s.push_str(&sentence(&t2.sentence));
+      for t3 in t2.children.iter() {
+        s.push_str(&sentence(&t3.sentence));
+      }
+    }
+  }
+}
+
+#[test]
+fn check_sentence_element_to_string_with() {
+  let para = test_paragraph(
+    r#"<Paragraph Num="1">
+  <ParagraphNum/>
+  <ParagraphSentence>
+    <Sentence Num="1"><Ruby>戸籍<Rt>こせき</Rt></Ruby>の<Line Style="solid">面積</Line>はＳ＝ａ<Sup>2</Sup>＋ｂ<Sub>1</Sub>とし、<ArithFormula Num="1">Ｘ＝Ｙ<Sup>3</Sup><Sentence Num="1">（注）</Sentence></ArithFormula>、第一条を次のように改める。<QuoteStruct><Sentence Num="1">第一条　削除</Sentence></QuoteStruct></Sentence>
+  </ParagraphSentence>
+</Paragraph>"#,
+  );
+  let contents = &para.sentence[0].contents;
+  assert_eq!(
+    sentence_element_to_str(contents),
+    "のはＳ＝ａ^{2}＋ｂ_{1}とし、Ｘ＝Ｙ^{3}、第一条を次のように改める。"
+  );
+  assert_eq!(
+    sentence_element_to_str(contents),
+    sentence_element_to_string_with(contents, &TextRenderOptions::default())
+  );
+  assert_eq!(
+    sentence_element_to_string_with(contents, &TextRenderOptions::plain()),
+    "戸籍の面積はＳ＝ａ2＋ｂ1とし、Ｘ＝Ｙ3（注）、第一条を次のように改める。第一条　削除"
+  );
+  let options = TextRenderOptions {
+    ruby: RubyStyle::BaseWithReading,
+    quote_struct: false,
+    arith_formula: FormulaStyle::Text,
+    ..TextRenderOptions::latex()
+  };
+  assert_eq!(
+    sentence_element_to_string_with(contents, &options),
+    "戸籍（こせき）の面積はＳ＝ａ^{2}＋ｂ_{1}とし、Ｘ＝Ｙ^{3}、第一条を次のように改める。"
+  );
+  let options = TextRenderOptions {
+    script: ScriptStyle::Omit,
+    line: false,
+    arith_formula: FormulaStyle::Omit,
+    ..TextRenderOptions::plain()
+  };
+  assert_eq!(
+    sentence_element_to_string_with(contents, &options),
+    "戸籍のはＳ＝ａ＋ｂとし、、第一条を次のように改める。第一条　削除"
+  );
 }
 
 #[test]
@@ -901,7 +2505,11 @@ fn check_para_to_text() {
             eda_numbers: Vec::new(),
             range_end_numbers: Vec::new()
           },
-          items: Vec::new()
+          items: Vec::new(),
+          column: None,
+          table_cell: None,
+          sentence_num: None,
+          sentence_function: None,
         },
         "被保佐人が次に掲げる行為をするには、その保佐人の同意を得なければならない。ただし、第九条ただし書に規定する行為については、この限りでない。".to_string()
       ),
@@ -912,7 +2520,11 @@ fn check_para_to_text() {
             eda_numbers: Vec::new(),
             range_end_numbers: Vec::new()
           },
-          items: vec![Some(ArticleNumber{base_number:1,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})]
+          items: vec![Some(ArticleNumber{base_number:1,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})],
+          column: None,
+          table_cell: None,
+          sentence_num: None,
+          sentence_function: None,
         },
         "元本を領収し、又は利用すること。".to_string()
       ),
@@ -923,7 +2535,11 @@ fn check_para_to_text() {
             eda_numbers: Vec::new(),
             range_end_numbers: Vec::new()
           },
-          items: vec![Some(ArticleNumber{base_number:2,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})]
+          items: vec![Some(ArticleNumber{base_number:2,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})],
+          column: None,
+          table_cell: None,
+          sentence_num: None,
+          sentence_function: None,
         },
         "主たる債務者が法人である場合の次に掲げる者".to_string()
       ),
@@ -935,7 +2551,11 @@ fn check_para_to_text() {
             range_end_numbers: Vec::new()
           },
           items: vec![Some(ArticleNumber{base_number:2,eda_numbers:Vec::new(),range_end_numbers:Vec::new()}),
-          Some(ArticleNumber{base_number:1,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})]
+          Some(ArticleNumber{base_number:1,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})],
+          column: None,
+          table_cell: None,
+          sentence_num: None,
+          sentence_function: None,
         },
         "主たる債務者の総株主の議決権（株主総会において決議をすることができる事項の全部につき議決権を行使することができない株式についての議決権を除く。以下この号において同じ。）の過半数を有する者".to_string()
       ),
@@ -946,7 +2566,11 @@ fn check_para_to_text() {
             eda_numbers: Vec::new(),
             range_end_numbers: Vec::new()
           },
-          items: vec![Some(ArticleNumber{base_number:3,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})]
+          items: vec![Some(ArticleNumber{base_number:3,eda_numbers:Vec::new(),range_end_numbers:Vec::new()})],
+          column: None,
+          table_cell: None,
+          sentence_num: None,
+          sentence_function: None,
         },
         "不動産その他重要な財産に関する権利の得喪を目的とする行為をすること。".to_string()
       ),
@@ -957,10 +2581,243 @@ fn check_para_to_text() {
             eda_numbers: Vec::new(),
             range_end_numbers: Vec::new()
           },
-          items: Vec::new()
+          items: Vec::new(),
+          column: None,
+          table_cell: None,
+          sentence_num: None,
+          sentence_function: None,
         },
         "家庭裁判所は、第十一条本文に規定する者又は保佐人若しくは保佐監督人の請求により、被保佐人が前項各号に掲げる行為以外の行為をする場合であってもその保佐人の同意を得なければならない旨の審判をすることができる。ただし、第九条ただし書に規定する行為については、この限りでない。".to_string()
       ),
     ]
   );
 }
+
+#[test]
+fn check_para_to_text_column_and_table() {
+  let law = test_law(
+    r#"<MainProvision>
+  <Article Num="2">
+    <ArticleTitle>第二条</ArticleTitle>
+    <Paragraph Num="1">
+      <ParagraphNum/>
+      <ParagraphSentence><Sentence Num="1">次の各号に掲げる用語の意義は、当該各号に定めるところによる。</Sentence></ParagraphSentence>
+      <Item Num="1">
+        <ItemTitle>一</ItemTitle>
+        <ItemSentence>
+          <Column Num="1"><Sentence Num="1">事業者</Sentence></Column>
+          <Column Num="2"><Sentence Num="1">商業を行う者をいう。</Sentence></Column>
+        </ItemSentence>
+      </Item>
+      <Item Num="2">
+        <ItemTitle>二</ItemTitle>
+        <ItemSentence>
+          <Table>
+            <TableHeaderRow><TableHeaderColumn>読み替える規定</TableHeaderColumn><TableHeaderColumn>読み替えられる字句</TableHeaderColumn></TableHeaderRow>
+            <TableRow>
+              <TableColumn><Sentence Num="1">第一条</Sentence></TableColumn>
+              <TableColumn><Sentence Num="1">甲</Sentence><Sentence Num="2">乙</Sentence></TableColumn>
+            </TableRow>
+          </Table>
+        </ItemSentence>
+      </Item>
+    </Paragraph>
+  </Article>
+</MainProvision>"#,
+  );
+  let entries = article_entry_list_from_lawbody("505AC0000000001", "テスト法", &law.law_body);
+  let paragraphs = &entries[0].paragraphs;
+  let num = |s: &str| ArticleNumber::from_num_str(s).unwrap();
+  let index = |item: &str, column, table_cell| TextIndex {
+    paragraph: num("1"),
+    items: vec![Some(num(item))],
+    column,
+    table_cell,
+    sentence_num: None,
+    sentence_function: None,
+  };
+  let cell = |row, column| Some(TableCellIndex { row, column });
+
+  let text_lst = text_list_from_paragraph(paragraphs);
+  assert_eq!(
+    text_lst[1..],
+    [
+      (
+        index("1", None, None),
+        "事業者　商業を行う者をいう。".to_string()
+      ),
+      (index("2", None, cell(1, 1)), "読み替える規定".to_string()),
+      (
+        index("2", None, cell(1, 2)),
+        "読み替えられる字句".to_string()
+      ),
+      (index("2", None, cell(2, 1)), "第一条".to_string()),
+      (index("2", None, cell(2, 2)), "甲乙".to_string()),
+    ]
+  );
+
+  let options = TextListOptions {
+    column_separator: "：".to_string(),
+    split_columns: true,
+    ..Default::default()
+  };
+  let text_lst = text_list_from_paragraph_with(paragraphs, &options);
+  assert_eq!(
+    text_lst[1..3],
+    [
+      (index("1", Some(1), None), "事業者".to_string()),
+      (
+        index("1", Some(2), None),
+        "商業を行う者をいう。".to_string()
+      ),
+    ]
+  );
+  let options = TextListOptions {
+    split_columns: false,
+    ..options
+  };
+  assert_eq!(
+    text_list_from_paragraph_with(paragraphs, &options)[1].1,
+    "事業者：商業を行う者をいう。"
+  );
+  let options = TextListOptions {
+    normalize: Some(NormalizeOptions::matching()),
+    ..Default::default()
+  };
+  assert_eq!(
+    text_list_from_paragraph_with(paragraphs, &options)[1].1,
+    "事業者商業を行う者をいう。"
+  );
+}
+
+#[test]
+fn check_text_list_with_source_map() {
+  let para = test_paragraph(
+    r#"<Paragraph Num="1">
+  <ParagraphNum/>
+  <ParagraphSentence>
+    <Sentence Function="main" Num="1">甲は、<Ruby>乙<Rt>おつ</Rt></Ruby>に通知する。</Sentence>
+    <Sentence Function="proviso" Num="2">ただし、この限りでない。</Sentence>
+  </ParagraphSentence>
+  <Item Num="1">
+    <ItemTitle>一</ItemTitle>
+    <ItemSentence>
+      <Column Num="1"><Sentence Num="1">甲</Sentence></Column>
+      <Column Num="2"><Sentence Num="1">乙をいう。</Sentence></Column>
+    </ItemSentence>
+  </Item>
+</Paragraph>"#,
+  );
+  let options = TextListOptions {
+    render: TextRenderOptions::plain(),
+    ..Default::default()
+  };
+  let lst = text_list_with_source_map(std::slice::from_ref(&para), &options);
+  let (_, text, source_map) = &lst[0];
+  assert_eq!(text, "甲は、乙に通知する。ただし、この限りでない。");
+  assert_eq!(source_map.spans.len(), 4);
+
+  let start = text.find("通知").unwrap();
+  let span = source_map.span(start..start + "通知".len());
+  assert_eq!(span.sources.len(), 1);
+  let source = &span.sources[0];
+  assert_eq!(source.range, start..start + "通知".len());
+  assert_eq!(source.sentence_num, Some(1));
+  assert_eq!(source.sentence_function, Some(SentenceFunction::Main));
+  assert_eq!(source.element_index, 2);
+  assert_eq!(source.element_kind, SentenceElementKind::String);
+  assert_eq!(source.original_offset(), Some("に".len()));
+
+  let start = text.find("乙").unwrap();
+  let span = source_map.span(start..start + "乙に".len());
+  assert_eq!(
+    span
+      .sources
+      .iter()
+      .map(|s| (s.element_kind, s.original_offset()))
+      .collect::<Vec<_>>(),
+    vec![
+      (SentenceElementKind::Ruby, None),
+      (SentenceElementKind::String, Some(0))
+    ]
+  );
+
+  let source = source_map.locate(text.find("限り").unwrap()).unwrap();
+  assert_eq!(source.sentence_index, 1);
+  assert_eq!(source.sentence_function, Some(SentenceFunction::Proviso));
+  assert!(source_map.locate(text.len()).is_none());
+
+  let (_, text, source_map) = &lst[1];
+  assert_eq!(text, "甲　乙をいう。");
+  let source = source_map.locate(text.find("いう").unwrap()).unwrap();
+  assert_eq!(source.column, Some(2));
+  assert_eq!(source.range, "甲　".len()..text.len());
+}
+
+#[test]
+fn check_text_list_split_sentences() {
+  let para = test_paragraph(
+    r#"<Paragraph Num="1">
+  <ParagraphNum/>
+  <ParagraphSentence>
+    <Sentence Function="main" Num="1">甲は、乙に通知する。</Sentence>
+    <Sentence Function="proviso" Num="2">ただし、この限りでない。</Sentence>
+  </ParagraphSentence>
+  <Item Num="1">
+    <ItemTitle>一</ItemTitle>
+    <ItemSentence>
+      <Sentence Num="1">丙を届け出ること。</Sentence>
+      <Sentence Num="2">この場合において、丁を添付する。</Sentence>
+    </ItemSentence>
+  </Item>
+</Paragraph>"#,
+  );
+  let options = TextListOptions {
+    split_sentences: true,
+    ..Default::default()
+  };
+  let lst = text_list_with_source_map(std::slice::from_ref(&para), &options);
+  assert_eq!(
+    lst
+      .iter()
+      .map(|(index, text, _)| (
+        index.items.len(),
+        index.sentence_num,
+        index.sentence_function.clone(),
+        index.sentence_part_name(2),
+        text.as_str()
+      ))
+      .collect::<Vec<_>>(),
+    vec![
+      (
+        0,
+        Some(1),
+        Some(SentenceFunction::Main),
+        Some("本文"),
+        "甲は、乙に通知する。"
+      ),
+      (
+        0,
+        Some(2),
+        Some(SentenceFunction::Proviso),
+        Some("ただし書"),
+        "ただし、この限りでない。"
+      ),
+      (1, Some(1), None, Some("前段"), "丙を届け出ること。"),
+      (
+        1,
+        Some(2),
+        None,
+        Some("後段"),
+        "この場合において、丁を添付する。"
+      ),
+    ]
+  );
+  assert_eq!(lst[1].2.spans[0].sentence_index, 1);
+  assert_eq!(lst[3].0.sentence_part_name(3), Some("中段"));
+  assert_eq!(lst[3].0.sentence_part_name(1), None);
+
+  let lst = text_list_from_paragraph(std::slice::from_ref(&para));
+  assert_eq!(lst.len(), 2);
+  assert_eq!(lst[0].0.sentence_num, None);
+}
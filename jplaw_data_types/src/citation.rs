@@ -0,0 +1,1364 @@
+//! 「第二十七条の二第一項第三号イ」や「前条第二項」のような条文の引用を解析する
+//! 「前条」「同項」のような相対的な引用は[`CitationResolver`]で条文の中の位置に解決する
+
+use crate::{
+  analysis::{AnalysisResultInfo, Reference},
+  article::{cmp_article_number, ArticleIndex, StructureKind, TextIndex, TextSourceMap},
+  law::LawNum,
+  numeral::{is_numeral, parse_number_ja, parse_roman},
+};
+use japanese_law_xml_schema::{
+  article_number::ArticleNumber, paragraph::Paragraph, sentence::SentenceFunction,
+};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use thiserror::Error;
+
+/// 号の細分で使われるイロハ順
+const IROHA: &str =
+  "イロハニホヘトチリヌルヲワカヨタレソツネナラムウヰノオクヤマケフコエテアサキユメミシヱヒモセス";
+
+/// 引用が示す階層
+/// 上の階層ほど小さい
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CitationLevel {
+  /// 「この法律」のような法令全体
+  Law,
+  /// 編
+  Part,
+  /// 章
+  Chapter,
+  /// 節
+  Section,
+  /// 款
+  Subsection,
+  /// 目
+  Division,
+  /// 条
+  Article,
+  /// 項
+  Paragraph,
+  /// 号
+  Item,
+  /// 号の細分
+  /// イロハは1、（１）は2、（ｉ）は3になる
+  Subitem(usize),
+}
+
+impl CitationLevel {
+  fn from_unit(c: char) -> Option<Self> {
+    match c {
+      '編' => Some(CitationLevel::Part),
+      '章' => Some(CitationLevel::Chapter),
+      '節' => Some(CitationLevel::Section),
+      '款' => Some(CitationLevel::Subsection),
+      '目' => Some(CitationLevel::Division),
+      '条' => Some(CitationLevel::Article),
+      '項' => Some(CitationLevel::Paragraph),
+      '号' => Some(CitationLevel::Item),
+      _ => None,
+    }
+  }
+}
+
+/// 引用先の番号の示し方
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CitationNumber {
+  /// 「第二条の二」のような番号
+  Number(ArticleNumber),
+  /// 「この章」「本条」
+  This,
+  /// 「同項」
+  Same,
+  /// 「前条」「前二項」
+  Previous(usize),
+  /// 「次条」
+  Next(usize),
+  /// 「前各号」
+  PreviousEach,
+  /// 「各号」
+  Each,
+}
+
+/// 引用の中の一つの階層
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationComponent {
+  /// 階層
+  pub level: CitationLevel,
+  /// 番号
+  pub number: CitationNumber,
+  /// 元の文字列でのバイト範囲
+  pub span: Range<usize>,
+}
+
+/// 「ただし書」のような文の部分
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SentencePart {
+  /// 本文
+  Main,
+  /// ただし書
+  Proviso,
+  /// 前段
+  First,
+  /// 中段
+  Middle,
+  /// 後段
+  Last,
+}
+
+/// 「第五条第二項第三号」のような一つの引用先
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationPath {
+  /// 元の文字列でのバイト範囲
+  pub span: Range<usize>,
+  /// 「附則第二条」のように附則を示しているかどうか
+  pub suppl_provision: bool,
+  /// 上の階層から並べたもの
+  /// 「第一項第一号及び第三号」の「第三号」のように省略された上の階層は前の引用先から補う
+  pub components: Vec<CitationComponent>,
+  /// 文の部分
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sentence_part: Option<SentencePart>,
+}
+
+impl CitationPath {
+  /// 指定した階層の要素
+  pub fn component(&self, level: CitationLevel) -> Option<&CitationComponent> {
+    self.components.iter().find(|c| c.level == level)
+  }
+
+  /// 指定した階層が番号で示されている場合はその番号
+  pub fn number(&self, level: CitationLevel) -> Option<&ArticleNumber> {
+    match &self.component(level)?.number {
+      CitationNumber::Number(num) => Some(num),
+      _ => None,
+    }
+  }
+
+  /// 条番号
+  pub fn article_number(&self) -> Option<&ArticleNumber> {
+    self.number(CitationLevel::Article)
+  }
+
+  /// 全ての階層が番号で示されているかどうか
+  pub fn is_absolute(&self) -> bool {
+    self
+      .components
+      .iter()
+      .all(|c| matches!(c.number, CitationNumber::Number(_)))
+  }
+
+  /// 条の中での位置
+  /// 項が省略されて号が示されている場合は第一項とする
+  /// 項と号が共に示されていない場合や、番号以外で示されている場合は`None`になる
+  pub fn text_index(&self) -> Option<TextIndex> {
+    let mut items = Vec::new();
+    for c in self.components.iter() {
+      if matches!(c.level, CitationLevel::Item | CitationLevel::Subitem(_)) {
+        match &c.number {
+          CitationNumber::Number(num) => items.push(Some(num.clone())),
+          _ => return None,
+        }
+      }
+    }
+    let paragraph = match self.component(CitationLevel::Paragraph) {
+      Some(CitationComponent {
+        number: CitationNumber::Number(num),
+        ..
+      }) => num.clone(),
+      Some(_) => return None,
+      None if !items.is_empty() => number(1),
+      None => return None,
+    };
+    let sentence_function = match self.sentence_part {
+      Some(SentencePart::Main) => Some(SentenceFunction::Main),
+      Some(SentencePart::Proviso) => Some(SentenceFunction::Proviso),
+      _ => None,
+    };
+    Some(TextIndex {
+      paragraph,
+      items,
+      column: None,
+      table_cell: None,
+      sentence_num: None,
+      sentence_function,
+    })
+  }
+}
+
+/// 引用の並びの中の一つ
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CitationItem {
+  /// 一つの引用先
+  Single(CitationPath),
+  /// 「第三条から第五条まで」のような範囲
+  Range {
+    /// 「まで」を含む元の文字列でのバイト範囲
+    span: Range<usize>,
+    start: CitationPath,
+    end: CitationPath,
+  },
+}
+
+impl CitationItem {
+  /// 元の文字列でのバイト範囲
+  pub fn span(&self) -> &Range<usize> {
+    match self {
+      CitationItem::Single(path) => &path.span,
+      CitationItem::Range { span, .. } => span,
+    }
+  }
+}
+
+/// 引用の並びのつなぎ方
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Conjunction {
+  /// 及び・並びに
+  And,
+  /// 又は・若しくは
+  Or,
+}
+
+/// 「第一号及び第三号」のような引用の並び
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Citation {
+  /// 元の文字列でのバイト範囲
+  pub span: Range<usize>,
+  /// 書かれた順の引用先
+  pub items: Vec<CitationItem>,
+  /// 「、」のみで並べた場合や引用先が一つの場合は`None`になる
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub conjunction: Option<Conjunction>,
+}
+
+fn number(n: usize) -> ArticleNumber {
+  ArticleNumber {
+    base_number: n,
+    eda_numbers: Vec::new(),
+    range_end_numbers: Vec::new(),
+  }
+}
+
+fn is_katakana(c: char) -> bool {
+  ('ァ'..='ヺ').contains(&c) || c == 'ー'
+}
+
+fn is_kanji(c: char) -> bool {
+  ('一'..='鿿').contains(&c) || c == '〇' || c == '々'
+}
+
+struct CitationParser<'a> {
+  text: &'a str,
+}
+
+impl CitationParser<'_> {
+  fn eat(&self, pos: usize, s: &str) -> Option<usize> {
+    self.text[pos..].starts_with(s).then_some(pos + s.len())
+  }
+
+  fn peek(&self, pos: usize) -> Option<char> {
+    self.text[pos..].chars().next()
+  }
+
+  fn prev(&self, pos: usize) -> Option<char> {
+    self.text[..pos].chars().next_back()
+  }
+
+  fn take_while(&self, pos: usize, f: impl Fn(char) -> bool) -> usize {
+    pos
+      + self.text[pos..]
+        .char_indices()
+        .find(|(_, c)| !f(*c))
+        .map(|(i, _)| i)
+        .unwrap_or(self.text.len() - pos)
+  }
+
+  fn number(&self, pos: usize) -> Option<(usize, usize)> {
+    let end = self.take_while(pos, is_numeral);
+    parse_number_ja(&self.text[pos..end]).map(|n| (n, end))
+  }
+
+  /// 「の二の三」のような枝番号を読む
+  fn eda(&self, mut pos: usize, base_number: usize) -> (ArticleNumber, usize) {
+    let mut num = number(base_number);
+    while let Some((n, end)) = self.eat(pos, "の").and_then(|p| self.number(p)) {
+      num.eda_numbers.push(n);
+      pos = end;
+    }
+    (num, pos)
+  }
+
+  fn unit(&self, pos: usize) -> Option<(CitationLevel, usize)> {
+    let c = self.peek(pos)?;
+    CitationLevel::from_unit(c).map(|level| (level, pos + c.len_utf8()))
+  }
+
+  fn component(
+    &self,
+    pos: usize,
+    prev: Option<CitationLevel>,
+  ) -> Option<(CitationComponent, usize)> {
+    let (level, number, end) = self
+      .numbered_component(pos)
+      .or_else(|| self.relative_component(pos))
+      .or_else(|| self.subitem_component(pos, prev?))?;
+    if prev.map_or(false, |prev| prev >= level) {
+      return None;
+    }
+    let component = CitationComponent {
+      level,
+      number,
+      span: pos..end,
+    };
+    Some((component, end))
+  }
+
+  fn numbered_component(&self, pos: usize) -> Option<(CitationLevel, CitationNumber, usize)> {
+    let (n, p) = self.number(self.eat(pos, "第")?)?;
+    let (level, p) = self.unit(p)?;
+    let (num, p) = self.eda(p, n);
+    Some((level, CitationNumber::Number(num), p))
+  }
+
+  fn relative_component(&self, pos: usize) -> Option<(CitationLevel, CitationNumber, usize)> {
+    let law_units = ["法律", "政令", "省令", "規則", "命令", "条例", "法"];
+    for (prefix, number) in [
+      ("この", CitationNumber::This),
+      ("本", CitationNumber::This),
+      ("同", CitationNumber::Same),
+    ] {
+      let Some(p) = self.eat(pos, prefix) else {
+        continue;
+      };
+      if let Some((level, p)) = self.unit(p) {
+        return Some((level, number, p));
+      }
+      if let Some(p) = law_units.iter().find_map(|u| self.eat(p, u)) {
+        // 「この法人」「同法施行令」のように、「法」の後に漢字が続くものは法令を指さない
+        // 「同法第三条」「本法及び」のように引用や接続詞が続くものは除く
+        let is_word = self.text[..p].ends_with('法')
+          && self
+            .peek(p)
+            .map_or(false, |c| is_kanji(c) && !"第附別及並又若中".contains(c));
+        if !is_word {
+          return Some((CitationLevel::Law, number, p));
+        }
+      }
+    }
+    if let Some(p) = self.eat(pos, "前各") {
+      let (level, p) = self.unit(p)?;
+      return Some((level, CitationNumber::PreviousEach, p));
+    }
+    if let Some(p) = self.eat(pos, "各") {
+      let (level, p) = self.unit(p)?;
+      return Some((level, CitationNumber::Each, p));
+    }
+    if let Some(p) = self.eat(pos, "前") {
+      let (n, p) = self.number(p).unwrap_or((1, p));
+      let (level, p) = self.unit(p)?;
+      return Some((level, CitationNumber::Previous(n), p));
+    }
+    if let Some(p) = self.eat(pos, "次") {
+      let (level, p) = self.unit(p)?;
+      return Some((level, CitationNumber::Next(1), p));
+    }
+    None
+  }
+
+  fn subitem_component(
+    &self,
+    pos: usize,
+    prev: CitationLevel,
+  ) -> Option<(CitationLevel, CitationNumber, usize)> {
+    match prev {
+      CitationLevel::Item => {
+        let c = self.peek(pos)?;
+        let n = IROHA.chars().position(|i| i == c)? + 1;
+        let p = pos + c.len_utf8();
+        if self.peek(p).map_or(false, is_katakana) {
+          return None;
+        }
+        let (num, p) = self.eda(p, n);
+        Some((CitationLevel::Subitem(1), CitationNumber::Number(num), p))
+      }
+      CitationLevel::Subitem(level @ (1 | 2)) => {
+        let p = self.eat(pos, "（").or_else(|| self.eat(pos, "("))?;
+        let end = self.take_while(p, |c| c != '）' && c != ')');
+        let s = &self.text[p..end];
+        let n = if level == 1 {
+          parse_number_ja(s).filter(|_| s.chars().all(|c| !is_kanji(c)))?
+        } else {
+          parse_roman(s)?
+        };
+        let p = self.eat(end, "）").or_else(|| self.eat(end, ")"))?;
+        let (num, p) = self.eda(p, n);
+        Some((
+          CitationLevel::Subitem(level + 1),
+          CitationNumber::Number(num),
+          p,
+        ))
+      }
+      _ => None,
+    }
+  }
+
+  fn path(&self, pos: usize) -> Option<(CitationPath, usize)> {
+    let (suppl_provision, mut p) = match self.eat(pos, "附則") {
+      Some(p) => (true, p),
+      None => (false, pos),
+    };
+    let mut components: Vec<CitationComponent> = Vec::new();
+    while let Some((component, end)) = self.component(p, components.last().map(|c| c.level)) {
+      components.push(component);
+      p = end;
+    }
+    if components.is_empty() {
+      return None;
+    }
+    let parts = [
+      ("本文", SentencePart::Main),
+      ("ただし書", SentencePart::Proviso),
+      ("前段", SentencePart::First),
+      ("中段", SentencePart::Middle),
+      ("後段", SentencePart::Last),
+    ];
+    let sentence_part = parts
+      .iter()
+      .find_map(|(s, part)| self.eat(p, s).map(|end| (*part, end)));
+    if let Some((_, end)) = sentence_part {
+      p = end;
+    }
+    let path = CitationPath {
+      span: pos..p,
+      suppl_provision,
+      components,
+      sentence_part: sentence_part.map(|(part, _)| part),
+    };
+    Some((path, p))
+  }
+
+  /// 省略された上の階層を前の引用先から補う
+  fn inherit(path: CitationPath, prev: Option<&CitationPath>) -> CitationPath {
+    let Some(prev) = prev else {
+      return path;
+    };
+    let first = &path.components[0];
+    if !matches!(first.number, CitationNumber::Number(_)) || path.suppl_provision {
+      return path;
+    }
+    let mut components = prev
+      .components
+      .iter()
+      .filter(|c| c.level < first.level)
+      .cloned()
+      .collect::<Vec<_>>();
+    components.extend(path.components);
+    CitationPath {
+      suppl_provision: prev.suppl_provision,
+      components,
+      ..path
+    }
+  }
+
+  fn item(&self, pos: usize, prev: Option<&CitationPath>) -> Option<(CitationItem, usize)> {
+    let (start, p) = self.path(pos)?;
+    let start = Self::inherit(start, prev);
+    let range_end = self
+      .eat(p, "から")
+      .and_then(|p| self.path(p))
+      .and_then(|(end, p)| self.eat(p, "まで").map(|p| (end, p)));
+    match range_end {
+      Some((end, p)) => {
+        let end = Self::inherit(end, Some(&start));
+        let span = start.span.start..p;
+        Some((CitationItem::Range { span, start, end }, p))
+      }
+      None => Some((CitationItem::Single(start), p)),
+    }
+  }
+
+  fn citation(&self, pos: usize) -> Option<Citation> {
+    let (item, mut p) = self.item(pos, None)?;
+    let mut items = vec![item];
+    let mut conjunction = None;
+    let separators = [
+      ("、", None),
+      ("及び", Some(Conjunction::And)),
+      ("並びに", Some(Conjunction::And)),
+      ("又は", Some(Conjunction::Or)),
+      ("若しくは", Some(Conjunction::Or)),
+    ];
+    'outer: loop {
+      let last = match items.last() {
+        Some(CitationItem::Single(path)) => path,
+        Some(CitationItem::Range { end, .. }) => end,
+        None => break,
+      };
+      for (sep, conj) in separators.iter() {
+        let Some(next) = self.eat(p, sep) else {
+          continue;
+        };
+        if let Some((item, end)) = self.item(next, Some(last)) {
+          items.push(item);
+          conjunction = conjunction.or(*conj);
+          p = end;
+          continue 'outer;
+        }
+      }
+      break;
+    }
+    Some(Citation {
+      span: pos..p,
+      items,
+      conjunction,
+    })
+  }
+
+  /// 単語の途中から始まる「本条」などを引用として扱わないようにする
+  fn can_start(&self, pos: usize) -> bool {
+    let starts_relative = ["本", "同", "各", "前", "次"]
+      .iter()
+      .any(|s| self.text[pos..].starts_with(s));
+    !(starts_relative && self.prev(pos).map_or(false, is_kanji))
+  }
+}
+
+/// 文字列の先頭にある条文の引用を読む
+pub fn parse_citation(text: &str) -> Option<Citation> {
+  CitationParser { text }.citation(0)
+}
+
+/// 文字列の中にある条文の引用を全て探す
+pub fn parse_citations(text: &str) -> Vec<Citation> {
+  let parser = CitationParser { text };
+  let mut v = Vec::new();
+  let mut pos = 0;
+  while let Some(c) = parser.peek(pos) {
+    match parser
+      .can_start(pos)
+      .then(|| parser.citation(pos))
+      .flatten()
+    {
+      Some(citation) => {
+        pos = citation.span.end;
+        v.push(citation);
+      }
+      None => pos += c.len_utf8(),
+    }
+  }
+  v
+}
+
+/// 引用を解決できなかった理由
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResolveError {
+  /// 前に引用が無いのに「同項」とした場合や、章の外で「この章」とした場合のように基準となる位置が無い
+  #[error("no context for relative citation")]
+  NoContext,
+  /// 引用先が条文の中に無い
+  #[error("citation target not found")]
+  NotFound,
+  /// 「同法」のような他の法令への引用
+  #[error("citation refers to another law")]
+  OtherLaw,
+  /// 解決に対応していない書き方
+  #[error("unsupported citation")]
+  Unsupported,
+}
+
+/// 解決された一つの引用先
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedLocation {
+  pub article_index: ArticleIndex,
+  /// 条全体を示す場合は`None`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub text_index: Option<TextIndex>,
+}
+
+/// 引用を解決した結果
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResolvedCitation {
+  /// 条・項・号
+  /// 「前二項」「前各号」や範囲の場合は書かれた順に複数並ぶ
+  Locations(Vec<ResolvedLocation>),
+  /// 「この章」のような編・章・節・款・目
+  Scope {
+    path: Vec<(StructureKind, ArticleNumber)>,
+    /// 附則の中の構造の場合は附則の名前
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suppl_provision_name: Option<String>,
+  },
+  /// 「この法律」のような法令全体
+  Law,
+}
+
+impl ResolvedCitation {
+  /// 条が引用先に含まれるかどうか
+  /// 法令全体の場合は同じ法令の条であることを呼び出し側で確かめる
+  pub fn contains(&self, index: &ArticleIndex) -> bool {
+    match self {
+      ResolvedCitation::Locations(lst) => lst.iter().any(|l| l.article_index == *index),
+      ResolvedCitation::Scope {
+        path,
+        suppl_provision_name,
+      } => {
+        !index.is_appdx()
+          && index.suppl_provision_name == *suppl_provision_name
+          && index.is_within(path)
+      }
+      ResolvedCitation::Law => true,
+    }
+  }
+}
+
+fn structure_kind(level: CitationLevel) -> Option<StructureKind> {
+  match level {
+    CitationLevel::Part => Some(StructureKind::Part),
+    CitationLevel::Chapter => Some(StructureKind::Chapter),
+    CitationLevel::Section => Some(StructureKind::Section),
+    CitationLevel::Subsection => Some(StructureKind::Subsection),
+    CitationLevel::Division => Some(StructureKind::Division),
+    _ => None,
+  }
+}
+
+/// 順に並んだ番号のリストから引用先の番号を選ぶ
+/// `current`は引用している位置の番号、`same`は直前の引用先の番号
+fn select(
+  nums: &[ArticleNumber],
+  number: &CitationNumber,
+  current: Option<&ArticleNumber>,
+  same: Option<&ArticleNumber>,
+) -> Result<Vec<ArticleNumber>, ResolveError> {
+  let position = || {
+    let current = current.ok_or(ResolveError::NoContext)?;
+    nums
+      .iter()
+      .position(|n| n == current)
+      .ok_or(ResolveError::NoContext)
+  };
+  let lst = match number {
+    CitationNumber::Number(n) => nums.iter().filter(|m| *m == n).cloned().collect(),
+    CitationNumber::This => vec![current.ok_or(ResolveError::NoContext)?.clone()],
+    CitationNumber::Same => vec![same.ok_or(ResolveError::NoContext)?.clone()],
+    CitationNumber::Previous(k) => {
+      let pos = position()?;
+      pos
+        .checked_sub(*k)
+        .map(|start| nums[start..pos].to_vec())
+        .unwrap_or_default()
+    }
+    CitationNumber::Next(k) => {
+      let pos = position()?;
+      nums
+        .get(pos + 1..pos + 1 + k)
+        .map(|s| s.to_vec())
+        .unwrap_or_default()
+    }
+    CitationNumber::PreviousEach => nums[..position()?].to_vec(),
+    CitationNumber::Each => nums.to_vec(),
+  };
+  if lst.is_empty() {
+    Err(ResolveError::NotFound)
+  } else {
+    Ok(lst)
+  }
+}
+
+/// 「前条」「同項」「この章」のような相対的な引用を、法令の中の位置に解決する
+///
+/// 「同項」のような引用は、直前に解決した一つの引用先を基準にする。
+/// 附則の中で「附則」と付けずに条番号で引用した場合は本則の条とし、
+/// 本則の中で「附則第二条」とした場合は原始附則の条とする。
+pub struct CitationResolver<'a> {
+  articles: &'a [AnalysisResultInfo<Vec<Paragraph>>],
+  last: Option<ResolvedLocation>,
+}
+
+impl<'a> CitationResolver<'a> {
+  /// `articles`には[`crate::article::article_list_from_lawbody`]で得た条文のリストを与える
+  pub fn new(articles: &'a [AnalysisResultInfo<Vec<Paragraph>>]) -> Self {
+    CitationResolver {
+      articles,
+      last: None,
+    }
+  }
+
+  /// 直前に解決した引用先
+  pub fn last(&self) -> Option<&ResolvedLocation> {
+    self.last.as_ref()
+  }
+
+  /// 直前の引用先を忘れる
+  pub fn reset(&mut self) {
+    self.last = None;
+  }
+
+  fn entry(&self, index: &ArticleIndex) -> Option<&'a AnalysisResultInfo<Vec<Paragraph>>> {
+    self.articles.iter().find(|a| a.article_index == *index)
+  }
+
+  /// 本則または指定した附則の条を順に返す
+  fn provision_articles(&self, suppl_provision_name: Option<&str>) -> Vec<&'a ArticleIndex> {
+    self
+      .articles
+      .iter()
+      .map(|a| &a.article_index)
+      .filter(|i| !i.is_appdx() && i.suppl_provision_name.as_deref() == suppl_provision_name)
+      .collect()
+  }
+
+  fn last_article(&self) -> Result<&ArticleIndex, ResolveError> {
+    self
+      .last
+      .as_ref()
+      .map(|l| &l.article_index)
+      .ok_or(ResolveError::NoContext)
+  }
+
+  /// 引用の並びを解決する
+  /// `current`と`text_index`には引用している位置を与える
+  pub fn resolve_citation(
+    &mut self,
+    citation: &Citation,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Vec<Result<ResolvedCitation, ResolveError>> {
+    citation
+      .items
+      .iter()
+      .map(|item| self.resolve_item(item, current, text_index))
+      .collect()
+  }
+
+  /// 引用の並びの中の一つを解決する
+  /// 範囲の場合は間にある条・項・号を全て並べる
+  pub fn resolve_item(
+    &mut self,
+    item: &CitationItem,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Result<ResolvedCitation, ResolveError> {
+    match item {
+      CitationItem::Single(path) => self.resolve_path(path, current, text_index),
+      CitationItem::Range { start, end, .. } => {
+        let start = self.resolve_single(start, current, text_index)?;
+        let end = self.resolve_single(end, current, text_index)?;
+        self.expand_range(&start, &end)
+      }
+    }
+  }
+
+  /// 一つの引用先を解決する
+  pub fn resolve_path(
+    &mut self,
+    path: &CitationPath,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Result<ResolvedCitation, ResolveError> {
+    let first = path.components.first().ok_or(ResolveError::Unsupported)?;
+    if first.level == CitationLevel::Law {
+      return match (&first.number, path.components.len()) {
+        (CitationNumber::Same, _) => Err(ResolveError::OtherLaw),
+        (CitationNumber::This, 1) => Ok(ResolvedCitation::Law),
+        _ => Err(ResolveError::Unsupported),
+      };
+    }
+    if first.level < CitationLevel::Article {
+      return self.resolve_scope(path, current);
+    }
+    let locations = self.resolve_locations(path, current, text_index)?;
+    if let [location] = locations.as_slice() {
+      self.last = Some(location.clone());
+    }
+    Ok(ResolvedCitation::Locations(locations))
+  }
+
+  fn resolve_single(
+    &mut self,
+    path: &CitationPath,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Result<ResolvedLocation, ResolveError> {
+    match self.resolve_path(path, current, text_index)? {
+      ResolvedCitation::Locations(mut lst) if lst.len() == 1 => Ok(lst.remove(0)),
+      _ => Err(ResolveError::Unsupported),
+    }
+  }
+
+  fn resolve_scope(
+    &self,
+    path: &CitationPath,
+    current: &ArticleIndex,
+  ) -> Result<ResolvedCitation, ResolveError> {
+    let suppl_provision_name = if path.suppl_provision || !path.is_absolute() {
+      Some(current.suppl_provision_name.clone().unwrap_or_default())
+        .filter(|_| path.suppl_provision || current.is_suppl_provision())
+    } else {
+      None
+    };
+    let articles = self.provision_articles(suppl_provision_name.as_deref());
+    let first_kind = path
+      .components
+      .first()
+      .and_then(|c| structure_kind(c.level))
+      .ok_or(ResolveError::Unsupported)?;
+    // 「第三章」のように上の構造が省略されている場合は引用している位置から補う
+    let mut scope = if articles.contains(&current) {
+      current
+        .path()
+        .filter(|(kind, _)| *kind < first_kind)
+        .map(|(kind, num)| (kind, num.clone()))
+        .collect()
+    } else {
+      Vec::new()
+    };
+    for c in path.components.iter() {
+      let kind = structure_kind(c.level).ok_or(ResolveError::Unsupported)?;
+      let num = match &c.number {
+        CitationNumber::Number(num) => num.clone(),
+        CitationNumber::This => current
+          .structure_number(kind)
+          .ok_or(ResolveError::NoContext)?
+          .clone(),
+        CitationNumber::Same => self
+          .last_article()?
+          .structure_number(kind)
+          .ok_or(ResolveError::NoContext)?
+          .clone(),
+        number @ (CitationNumber::Previous(1) | CitationNumber::Next(1)) => {
+          let mut nums: Vec<ArticleNumber> = Vec::new();
+          for index in articles.iter().filter(|i| i.is_within(&scope)) {
+            if let Some(num) = index.structure_number(kind) {
+              if nums.last() != Some(num) {
+                nums.push(num.clone());
+              }
+            }
+          }
+          select(&nums, number, current.structure_number(kind), None)?.remove(0)
+        }
+        _ => return Err(ResolveError::Unsupported),
+      };
+      scope.push((kind, num));
+    }
+    if !articles.iter().any(|i| i.is_within(&scope)) {
+      return Err(ResolveError::NotFound);
+    }
+    Ok(ResolvedCitation::Scope {
+      path: scope,
+      suppl_provision_name,
+    })
+  }
+
+  fn resolve_locations(
+    &self,
+    path: &CitationPath,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Result<Vec<ResolvedLocation>, ResolveError> {
+    let sentence_function = match path.sentence_part {
+      Some(SentencePart::Main) => Some(SentenceFunction::Main),
+      Some(SentencePart::Proviso) => Some(SentenceFunction::Proviso),
+      _ => None,
+    };
+    let to_text_index = |paragraph: ArticleNumber, items: Vec<Option<ArticleNumber>>| TextIndex {
+      paragraph,
+      items,
+      column: None,
+      table_cell: None,
+      sentence_num: None,
+      sentence_function: sentence_function.clone(),
+    };
+    let mut components = path.components.iter().peekable();
+    let current_suppl = current.suppl_provision_name.as_deref();
+
+    // 条
+    let articles: Vec<ArticleIndex> = match components.peek() {
+      Some(c) if c.level == CitationLevel::Article => {
+        let number = &components.next().unwrap().number;
+        if *number == CitationNumber::Same {
+          vec![self.last_article()?.clone()]
+        } else {
+          let suppl = if path.suppl_provision {
+            Some(current_suppl.unwrap_or_default())
+          } else if matches!(number, CitationNumber::Number(_)) {
+            None
+          } else {
+            current_suppl
+          };
+          let lst = self.provision_articles(suppl);
+          let nums = lst
+            .iter()
+            .map(|i| i.article_number.clone())
+            .collect::<Vec<_>>();
+          let here = lst.contains(&current).then_some(&current.article_number);
+          select(&nums, number, here, None)?
+            .into_iter()
+            .filter_map(|n| lst.iter().find(|i| i.article_number == n).cloned())
+            .cloned()
+            .collect()
+        }
+      }
+      Some(c) if c.number == CitationNumber::Same => vec![self.last_article()?.clone()],
+      _ if path.suppl_provision && !current.is_suppl_provision() => {
+        // 「附則第二項」のような条の無い原始附則
+        match self.provision_articles(Some("")).as_slice() {
+          [index] => vec![(*index).clone()],
+          _ => return Err(ResolveError::NotFound),
+        }
+      }
+      _ => vec![current.clone()],
+    };
+    if components.peek().is_none() || articles.len() > 1 {
+      if components.peek().is_some() {
+        return Err(ResolveError::Unsupported);
+      }
+      // 「前条ただし書」は条の唯一の項の文を示す
+      return Ok(
+        articles
+          .into_iter()
+          .map(|article_index| {
+            let text_index = sentence_function
+              .as_ref()
+              .and_then(|_| self.entry(&article_index)?.result.first())
+              .map(|p| to_text_index(p.num.clone(), Vec::new()));
+            ResolvedLocation {
+              article_index,
+              text_index,
+            }
+          })
+          .collect(),
+      );
+    }
+    let article = articles.into_iter().next().ok_or(ResolveError::NotFound)?;
+    let entry = self.entry(&article).ok_or(ResolveError::NotFound)?;
+    let here = text_index.filter(|_| article == *current);
+    let last = self
+      .last
+      .as_ref()
+      .filter(|l| l.article_index == article)
+      .and_then(|l| l.text_index.as_ref());
+
+    // 項
+    let paragraph_nums = entry
+      .result
+      .iter()
+      .map(|p| p.num.clone())
+      .collect::<Vec<_>>();
+    let paragraphs = match components.next_if(|c| c.level == CitationLevel::Paragraph) {
+      Some(c) => select(
+        &paragraph_nums,
+        &c.number,
+        here.map(|t| &t.paragraph),
+        last.map(|t| &t.paragraph),
+      )?,
+      // 「第三号」のように項が省略された場合
+      None => {
+        let c = components.peek().ok_or(ResolveError::Unsupported)?;
+        let paragraph = match (&c.number, here, last) {
+          (CitationNumber::Same, _, Some(t)) => t.paragraph.clone(),
+          (CitationNumber::Same, _, None) => return Err(ResolveError::NoContext),
+          (_, Some(t), _) => t.paragraph.clone(),
+          _ => paragraph_nums
+            .first()
+            .ok_or(ResolveError::NotFound)?
+            .clone(),
+        };
+        vec![paragraph]
+      }
+    };
+    if components.peek().is_none() || paragraphs.len() > 1 {
+      if components.peek().is_some() {
+        return Err(ResolveError::Unsupported);
+      }
+      return Ok(
+        paragraphs
+          .into_iter()
+          .map(|p| ResolvedLocation {
+            article_index: article.clone(),
+            text_index: Some(to_text_index(p, Vec::new())),
+          })
+          .collect(),
+      );
+    }
+    let paragraph_num = paragraphs
+      .into_iter()
+      .next()
+      .ok_or(ResolveError::NotFound)?;
+    let paragraph = entry
+      .result
+      .iter()
+      .find(|p| p.num == paragraph_num)
+      .ok_or(ResolveError::NotFound)?;
+    let first_item = |t: &TextIndex| t.items.first().cloned().flatten();
+    let here = here
+      .filter(|t| t.paragraph == paragraph_num)
+      .and_then(first_item);
+    let last = last
+      .filter(|t| t.paragraph == paragraph_num)
+      .and_then(first_item);
+
+    // 号
+    let c = components.next().ok_or(ResolveError::Unsupported)?;
+    if c.level != CitationLevel::Item {
+      return Err(ResolveError::Unsupported);
+    }
+    let item_nums = paragraph
+      .children
+      .iter()
+      .filter_map(|i| i.num.clone())
+      .collect::<Vec<_>>();
+    let items = select(&item_nums, &c.number, here.as_ref(), last.as_ref())?;
+    if items.len() > 1 {
+      if components.peek().is_some() {
+        return Err(ResolveError::Unsupported);
+      }
+      return Ok(
+        items
+          .into_iter()
+          .map(|i| ResolvedLocation {
+            article_index: article.clone(),
+            text_index: Some(to_text_index(paragraph_num.clone(), vec![Some(i)])),
+          })
+          .collect(),
+      );
+    }
+    let mut item_path = items.into_iter().map(Some).collect::<Vec<_>>();
+    // 号の細分は番号で示されたものだけを扱う
+    for c in components {
+      match (&c.level, &c.number) {
+        (CitationLevel::Subitem(_), CitationNumber::Number(num)) => {
+          item_path.push(Some(num.clone()))
+        }
+        _ => return Err(ResolveError::Unsupported),
+      }
+    }
+    Ok(vec![ResolvedLocation {
+      article_index: article,
+      text_index: Some(to_text_index(paragraph_num, item_path)),
+    }])
+  }
+
+  fn expand_range(
+    &self,
+    start: &ResolvedLocation,
+    end: &ResolvedLocation,
+  ) -> Result<ResolvedCitation, ResolveError> {
+    let within = |num: &ArticleNumber, s: &ArticleNumber, e: &ArticleNumber| {
+      cmp_article_number(s, num).is_le() && cmp_article_number(num, e).is_le()
+    };
+    let lst: Vec<ResolvedLocation> = match (&start.text_index, &end.text_index) {
+      (None, None) => {
+        let range = &start.article_index..=&end.article_index;
+        self
+          .provision_articles(start.article_index.suppl_provision_name.as_deref())
+          .into_iter()
+          .filter(|i| range.contains(i))
+          .map(|i| ResolvedLocation {
+            article_index: i.clone(),
+            text_index: None,
+          })
+          .collect()
+      }
+      (Some(s), Some(e)) if start.article_index == end.article_index => {
+        let entry = self
+          .entry(&start.article_index)
+          .ok_or(ResolveError::NotFound)?;
+        let location = |paragraph: &ArticleNumber, items| ResolvedLocation {
+          article_index: start.article_index.clone(),
+          text_index: Some(TextIndex {
+            paragraph: paragraph.clone(),
+            items,
+            column: None,
+            table_cell: None,
+            sentence_num: None,
+            sentence_function: None,
+          }),
+        };
+        match (s.items.as_slice(), e.items.as_slice()) {
+          ([], []) => entry
+            .result
+            .iter()
+            .filter(|p| within(&p.num, &s.paragraph, &e.paragraph))
+            .map(|p| location(&p.num, Vec::new()))
+            .collect(),
+          ([Some(si)], [Some(ei)]) if s.paragraph == e.paragraph => entry
+            .result
+            .iter()
+            .find(|p| p.num == s.paragraph)
+            .ok_or(ResolveError::NotFound)?
+            .children
+            .iter()
+            .filter_map(|i| i.num.as_ref())
+            .filter(|num| within(num, si, ei))
+            .map(|num| location(&s.paragraph, vec![Some(num.clone())]))
+            .collect(),
+          _ => return Err(ResolveError::Unsupported),
+        }
+      }
+      _ => return Err(ResolveError::Unsupported),
+    };
+    if lst.is_empty() {
+      Err(ResolveError::NotFound)
+    } else {
+      Ok(ResolvedCitation::Locations(lst))
+    }
+  }
+
+  /// 文の中の引用を全て解決して[`Reference`]のリストにする
+  ///
+  /// 「民法第五条」「刑法（明治四十年法律第四十五号）第三条」のように法令名や法令番号の直後にある引用は他の法令への引用とみなして除く。
+  /// 編・章などや法令全体への引用は`ref_index`を`None`にする。
+  /// 解決できなかった引用は含めない。
+  pub fn references(
+    &mut self,
+    text: &str,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Vec<Reference> {
+    self
+      .references_with_range(text, current, text_index)
+      .into_iter()
+      .map(|(_, reference)| reference)
+      .collect()
+  }
+
+  /// [`CitationResolver::references`]と同じ引用を、文字列の範囲とともに解析結果として返す
+  ///
+  /// `text`と`source_map`は[`crate::article::text_list_with_source_map`]で得たものを渡す。
+  pub fn references_with_source_map(
+    &mut self,
+    text: &str,
+    source_map: &TextSourceMap,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Vec<AnalysisResultInfo<Reference>> {
+    self
+      .references_with_range(text, current, text_index)
+      .into_iter()
+      .map(|(range, reference)| {
+        AnalysisResultInfo::new(current.clone(), text_index.cloned(), reference)
+          .with_span(source_map.span(range))
+      })
+      .collect()
+  }
+
+  fn references_with_range(
+    &mut self,
+    text: &str,
+    current: &ArticleIndex,
+    text_index: Option<&TextIndex>,
+  ) -> Vec<(Range<usize>, Reference)> {
+    let mut v = Vec::new();
+    for citation in parse_citations(text) {
+      if follows_law_name(&text[..citation.span.start]) {
+        continue;
+      }
+      for item in citation.items.iter() {
+        let span = item.span().clone();
+        let Ok(resolved) = self.resolve_item(item, current, text_index) else {
+          continue;
+        };
+        let reference = |ref_index| Reference {
+          ref_name: current.law_name.clone(),
+          ref_index,
+          base_name: current.law_name.clone(),
+          base_index: Some(current.clone()),
+          text: text[span.clone()].to_string(),
+        };
+        match resolved {
+          ResolvedCitation::Locations(mut lst) => {
+            lst.dedup_by(|a, b| a.article_index == b.article_index);
+            v.extend(
+              lst
+                .into_iter()
+                .map(|l| (span.clone(), reference(Some(l.article_index)))),
+            );
+          }
+          _ => v.push((span.clone(), reference(None))),
+        }
+      }
+    }
+    v
+  }
+}
+
+/// 「民法」「刑法（明治四十年法律第四十五号）」のような法令名や法令番号で終わっているかどうか
+/// 括弧書きは中身が法令番号か法令名の場合に限る
+fn follows_law_name(text: &str) -> bool {
+  let is_law_name = |s: &str| {
+    s.chars()
+      .next_back()
+      .map_or(false, |c| "法令律則例".contains(c))
+  };
+  match text.strip_suffix('）') {
+    Some(rest) => rest.rfind('（').map_or(false, |i| {
+      let inner = &rest[i + '（'.len_utf8()..];
+      inner.parse::<LawNum>().is_ok() || is_law_name(inner)
+    }),
+    None => is_law_name(text),
+  }
+}
+
+#[test]
+fn check_follows_law_name() {
+  assert!(follows_law_name("前条及び刑法"));
+  assert!(follows_law_name("刑法（明治四十年法律第四十五号）"));
+  assert!(follows_law_name("同条（民法）"));
+  assert!(!follows_law_name("甲（乙をいう。）"));
+  assert!(!follows_law_name("この場合において（"));
+  assert!(!follows_law_name(""));
+}
+
+#[cfg(test)]
+fn num(s: &str) -> ArticleNumber {
+  ArticleNumber::from_num_str(s).unwrap()
+}
+
+#[test]
+fn check_parse_citation_path() {
+  let text = "第二十七条の二第一項第三号イ";
+  let citation = parse_citation(text).unwrap();
+  assert_eq!(citation.span, 0..text.len());
+  let CitationItem::Single(path) = &citation.items[0] else {
+    panic!()
+  };
+  assert_eq!(
+    path
+      .components
+      .iter()
+      .map(|c| (c.level, c.number.clone()))
+      .collect::<Vec<_>>(),
+    vec![
+      (CitationLevel::Article, CitationNumber::Number(num("27_2"))),
+      (CitationLevel::Paragraph, CitationNumber::Number(num("1"))),
+      (CitationLevel::Item, CitationNumber::Number(num("3"))),
+      (CitationLevel::Subitem(1), CitationNumber::Number(num("1"))),
+    ]
+  );
+  assert_eq!(path.article_number(), Some(&num("27_2")));
+  let index = path.text_index().unwrap();
+  assert_eq!(index.paragraph, num("1"));
+  assert_eq!(index.items, vec![Some(num("3")), Some(num("1"))]);
+  assert_eq!(
+    path.components[1].span,
+    "第二十七条の二".len().."第二十七条の二第一項".len()
+  );
+
+  let path = |s: &str| match parse_citation(s).unwrap().items.remove(0) {
+    CitationItem::Single(path) => path,
+    _ => panic!(),
+  };
+  let p = path("前条第二項");
+  assert_eq!(p.components[0].number, CitationNumber::Previous(1));
+  assert!(!p.is_absolute());
+  assert_eq!(p.text_index().unwrap().paragraph, num("2"));
+  let p = path("同項ただし書");
+  assert_eq!(p.components[0].level, CitationLevel::Paragraph);
+  assert_eq!(p.components[0].number, CitationNumber::Same);
+  assert_eq!(p.sentence_part, Some(SentencePart::Proviso));
+  let p = path("前二項");
+  assert_eq!(p.components[0].number, CitationNumber::Previous(2));
+  let p = path("前項各号");
+  assert_eq!(p.components[1].number, CitationNumber::Each);
+  assert_eq!(p.components[1].level, CitationLevel::Item);
+  let p = path("前各号");
+  assert_eq!(p.components[0].number, CitationNumber::PreviousEach);
+  let p = path("この法律");
+  assert_eq!(p.components[0].level, CitationLevel::Law);
+  let p = path("附則第三条第一項本文");
+  assert!(p.suppl_provision);
+  assert_eq!(
+    p.text_index().unwrap().sentence_function,
+    Some(SentenceFunction::Main)
+  );
+  let p = path("第五条第三号");
+  assert_eq!(p.text_index().unwrap().paragraph, num("1"));
+  let p = path("第一号ロ（２）（ii）");
+  assert_eq!(
+    p.text_index().unwrap().items,
+    vec![
+      Some(num("1")),
+      Some(num("2")),
+      Some(num("2")),
+      Some(num("2"))
+    ]
+  );
+  assert_eq!(
+    path("第二編第三章").components[1].level,
+    CitationLevel::Chapter
+  );
+  assert!(parse_citation("第三者").is_none());
+  assert!(parse_citation("次に掲げる").is_none());
+}
+
+#[test]
+fn check_parse_citation_list_and_range() {
+  let citation = parse_citation("第三条から第五条まで").unwrap();
+  let CitationItem::Range { span, start, end } = &citation.items[0] else {
+    panic!()
+  };
+  assert_eq!(span, &(0.."第三条から第五条まで".len()));
+  assert_eq!(start.article_number(), Some(&num("3")));
+  assert_eq!(end.article_number(), Some(&num("5")));
+
+  let citation = parse_citation("第一項第一号及び第三号").unwrap();
+  assert_eq!(citation.conjunction, Some(Conjunction::And));
+  assert_eq!(citation.items.len(), 2);
+  let CitationItem::Single(second) = &citation.items[1] else {
+    panic!()
+  };
+  assert_eq!(
+    second.span,
+    "第一項第一号及び".len().."第一項第一号及び第三号".len()
+  );
+  assert_eq!(second.text_index().unwrap().items, vec![Some(num("3"))]);
+  assert_eq!(second.text_index().unwrap().paragraph, num("1"));
+
+  let citation = parse_citation("第二条第一項第二号から第四号まで").unwrap();
+  let CitationItem::Range { end, .. } = &citation.items[0] else {
+    panic!()
+  };
+  assert_eq!(end.article_number(), Some(&num("2")));
+  assert_eq!(end.text_index().unwrap().items, vec![Some(num("4"))]);
+
+  let citation = parse_citation("第三条、第五条又は第七条の規定").unwrap();
+  assert_eq!(citation.items.len(), 3);
+  assert_eq!(citation.conjunction, Some(Conjunction::Or));
+  assert_eq!(citation.span.end, "第三条、第五条又は第七条".len());
+}
+
+#[test]
+fn check_parse_citations() {
+  let text = "前条第二項の規定は、第九条第一項、日本条約及び同項ただし書について準用する。";
+  let lst = parse_citations(text);
+  assert_eq!(
+    lst
+      .iter()
+      .map(|c| &text[c.span.clone()])
+      .collect::<Vec<_>>(),
+    vec!["前条第二項", "第九条第一項", "同項ただし書"]
+  );
+}
+
+#[test]
+fn check_parse_citations_law() {
+  let text = "この法人は、同法人の業務及び本法第三条並びに同法施行令について定める。";
+  let lst = parse_citations(text);
+  assert_eq!(
+    lst
+      .iter()
+      .map(|c| &text[c.span.clone()])
+      .collect::<Vec<_>>(),
+    vec!["本法第三条"]
+  );
+  let text = "この法律及び同法の規定";
+  let lst = parse_citations(text);
+  assert_eq!(
+    lst
+      .iter()
+      .map(|c| &text[c.span.clone()])
+      .collect::<Vec<_>>(),
+    vec!["この法律及び同法"]
+  );
+}
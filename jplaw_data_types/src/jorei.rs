@@ -1,6 +1,9 @@
 //! 条例に使うデータ構造
 
-use crate::law::Date;
+use crate::{
+  law::Date,
+  normalize::{normalize, NormalizeOptions},
+};
 use serde::{Deserialize, Serialize};
 
 /// 条例の一覧のための情報
@@ -78,3 +81,10 @@ pub struct JoreiData {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub updated_date_s: Option<String>,
 }
+
+impl JoreiData {
+  /// 設定に従って表記をそろえた本文
+  pub fn normalized_content(&self, options: &NormalizeOptions) -> Option<String> {
+    self.content.as_ref().map(|s| normalize(s, options))
+  }
+}
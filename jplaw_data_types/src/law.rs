@@ -1,8 +1,10 @@
 //! 法律そのものに関する情報
 
+use crate::numeral::{parse_number_ja, to_kansuji, to_kansuji_positional};
 use japanese_law_xml_schema::law::Era;
 use serde::{Deserialize, Serialize};
 use std::{fmt::Display, str::FromStr};
+use thiserror::Error;
 
 /// 日付（元号）
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,7 +17,91 @@ pub struct Date {
   pub day: Option<usize>,
 }
 
+/// 日付の構築・解析時のエラー
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DateError {
+  /// 0年などの不正な年
+  #[error("invalid year: {0}")]
+  InvalidYear(usize),
+  /// 1〜12以外の月
+  #[error("invalid month: {0}")]
+  InvalidMonth(usize),
+  /// その月に存在しない日
+  #[error("invalid day: {day} (month {month})")]
+  InvalidDay { month: usize, day: usize },
+  /// 月が無いのに日がある
+  #[error("day is given without month")]
+  DayWithoutMonth,
+  /// 元号の期間外の日付
+  #[error("out of era range: {era:?} {year}")]
+  OutOfEra {
+    era: Era,
+    year: usize,
+    month: Option<usize>,
+    day: Option<usize>,
+  },
+  /// 月や日が無く、変換できない
+  #[error("month or day is missing")]
+  Incomplete,
+  /// 明治5年12月2日以前の太陰太陽暦の期間の日付で、和暦と西暦の間で変換できない
+  #[error("date in the lunisolar calendar")]
+  Lunisolar,
+  /// 和暦の表記として読めない文字列
+  #[error("invalid date format: {0}")]
+  InvalidFormat(String),
+  /// 明治改元（1868年10月23日）より前の西暦
+  #[error("before Meiji: {year}-{month:02}-{day:02}")]
+  BeforeMeiji {
+    year: usize,
+    month: usize,
+    day: usize,
+  },
+}
+
+/// 元号の並び順
+fn era_index(era: &Era) -> usize {
+  match era {
+    Era::Meiji => 0,
+    Era::Taisho => 1,
+    Era::Showa => 2,
+    Era::Heisei => 3,
+    Era::Reiwa => 4,
+  }
+}
+
+/// 西暦の`(年, 月, 日)`
+type AdDate = (usize, usize, usize);
+
+/// 元号の期間を西暦で返す
+/// 明治は改元の詔により慶応4年1月1日に遡って適用されているため、1868年1月1日を始まりとする
+fn era_range(era: &Era) -> (AdDate, Option<AdDate>) {
+  match era {
+    Era::Meiji => ((1868, 1, 1), Some((1912, 7, 29))),
+    Era::Taisho => ((1912, 7, 30), Some((1926, 12, 24))),
+    Era::Showa => ((1926, 12, 25), Some((1989, 1, 7))),
+    Era::Heisei => ((1989, 1, 8), Some((2019, 4, 30))),
+    Era::Reiwa => ((2019, 5, 1), None),
+  }
+}
+
+/// 閏年の判定
+fn is_leap_year(year: usize) -> bool {
+  (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// グレゴリオ暦での月の日数
+fn days_in_month(year: usize, month: usize) -> usize {
+  match month {
+    1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+    4 | 6 | 9 | 11 => 30,
+    2 if is_leap_year(year) => 29,
+    _ => 28,
+  }
+}
+
 impl Date {
+  /// 値の検証を行わずに構築する
+  /// 外部のデータから構築する場合は[`Date::try_new`]を使う
   pub fn new(era: Era, year: usize, month: Option<usize>, day: Option<usize>) -> Self {
     Self {
       era,
@@ -25,6 +111,77 @@ impl Date {
     }
   }
 
+  /// 値を検証して構築する
+  pub fn try_new(
+    era: Era,
+    year: usize,
+    month: Option<usize>,
+    day: Option<usize>,
+  ) -> Result<Self, DateError> {
+    let date = Self::new(era, year, month, day);
+    date.validate()?;
+    Ok(date)
+  }
+
+  /// 日付として正しいかを検証する
+  ///
+  /// - 月は1〜12、日はその月の日数の範囲内である
+  /// - 元号の期間内である（昭和64年2月や平成元年1月7日はエラー）
+  /// - 明治5年12月2日までは太陰太陽暦であるため、日は30日までを許容する
+  pub fn validate(&self) -> Result<(), DateError> {
+    if self.year == 0 {
+      return Err(DateError::InvalidYear(self.year));
+    }
+    let ad = self.get_ad();
+    if let Some(month) = self.month {
+      if !(1..=12).contains(&month) {
+        return Err(DateError::InvalidMonth(month));
+      }
+    }
+    match (self.month, self.day) {
+      (None, Some(_)) => return Err(DateError::DayWithoutMonth),
+      (Some(month), Some(day)) => {
+        let max_day = if ad < 1873 {
+          if ad == 1872 && month == 12 {
+            2
+          } else {
+            30
+          }
+        } else {
+          days_in_month(ad, month)
+        };
+        if !(1..=max_day).contains(&day) {
+          return Err(DateError::InvalidDay { month, day });
+        }
+      }
+      _ => (),
+    }
+    let (first, last) = self.ad_range();
+    let (era_start, era_end) = era_range(&self.era);
+    let is_after_end = era_end.map(|end| end < first).unwrap_or(false);
+    if last < era_start || is_after_end {
+      return Err(DateError::OutOfEra {
+        era: self.era.clone(),
+        year: self.year,
+        month: self.month,
+        day: self.day,
+      });
+    }
+    Ok(())
+  }
+
+  /// 月や日が無い場合も含め、日付が表す期間の最初と最後の日を西暦で返す
+  fn ad_range(&self) -> (AdDate, AdDate) {
+    let ad = self.get_ad();
+    let first = (ad, self.month.unwrap_or(1), self.day.unwrap_or(1));
+    let last = {
+      let month = self.month.unwrap_or(12);
+      let day = self.day.unwrap_or_else(|| days_in_month(ad, month));
+      (ad, month, day)
+    };
+    (first, last)
+  }
+
   /// 西暦の取得
   pub fn get_ad(&self) -> usize {
     use Era::*;
@@ -38,25 +195,41 @@ impl Date {
   }
 
   /// 西暦からの構築
+  /// 不正な日付の場合はpanicするため、[`Date::try_from_ad`]を推奨する
   pub fn gen_from_ad(year: usize, month: usize, day: usize) -> Self {
+    Self::try_from_ad(year, month, day).expect("invalid date")
+  }
+
+  /// 西暦（グレゴリオ暦）の年月日から構築する
+  /// 改元日以降は新しい元号で表す
+  /// 明治5年12月2日までの和暦は太陰太陽暦であるため、1873年1月1日より前の日付はエラーになる
+  pub fn try_from_ad(year: usize, month: usize, day: usize) -> Result<Self, DateError> {
+    if !(1..=12).contains(&month) {
+      return Err(DateError::InvalidMonth(month));
+    }
+    if !(1..=days_in_month(year, month)).contains(&day) {
+      return Err(DateError::InvalidDay { month, day });
+    }
+    let t = (year, month, day);
+    if t < (1868, 10, 23) {
+      return Err(DateError::BeforeMeiji { year, month, day });
+    }
+    if t < (1873, 1, 1) {
+      return Err(DateError::Lunisolar);
+    }
+    Ok(Self::from_ad_unchecked(t))
+  }
+
+  /// 元号の期間の表と同じ形式の年月日から、暦の検証を行わずに構築する
+  fn from_ad_unchecked((year, month, day): AdDate) -> Self {
     use Era::*;
-    let t = year * 10000 + month * 100 + day;
-    let (era, year) = if (18681023..=19120729).contains(&t) {
-      (Meiji, year - 1867)
-    } else if (19120730..=19261224).contains(&t) {
-      (Taisho, year - 1911)
-    } else if (19261225..=19890107).contains(&t) {
-      (Showa, year - 1925)
-    } else if (19890108..=20190430).contains(&t) {
-      (Heisei, year - 1988)
-    } else if 20190501 <= t {
-      (Reiwa, year - 2018)
-    } else {
-      unreachable!()
-    };
+    let era = [Reiwa, Heisei, Showa, Taisho, Meiji]
+      .into_iter()
+      .find(|era| era_range(era).0 <= (year, month, day))
+      .unwrap_or(Meiji);
     Self {
+      year: year - era_range(&era).0 .0 + 1,
       era,
-      year,
       month: Some(month),
       day: Some(day),
     }
@@ -65,7 +238,7 @@ impl Date {
 
 #[test]
 fn check_date_gen() {
-  let d = Date::gen_from_ad(1923, 06, 20);
+  let d = Date::gen_from_ad(1923, 6, 20);
   assert_eq!(
     d,
     Date {
@@ -77,22 +250,277 @@ fn check_date_gen() {
   )
 }
 
+#[test]
+fn check_date_try_from_ad() {
+  assert_eq!(
+    Date::try_from_ad(1989, 1, 7),
+    Ok(Date::new(Era::Showa, 64, Some(1), Some(7)))
+  );
+  assert_eq!(
+    Date::try_from_ad(1989, 1, 8),
+    Ok(Date::new(Era::Heisei, 1, Some(1), Some(8)))
+  );
+  assert_eq!(
+    Date::try_from_ad(2019, 5, 1),
+    Ok(Date::new(Era::Reiwa, 1, Some(5), Some(1)))
+  );
+  assert_eq!(Date::try_from_ad(1868, 10, 23), Err(DateError::Lunisolar));
+  assert_eq!(Date::try_from_ad(1872, 12, 3), Err(DateError::Lunisolar));
+  assert_eq!(
+    Date::try_from_ad(1873, 1, 1),
+    Ok(Date::new(Era::Meiji, 6, Some(1), Some(1)))
+  );
+  assert_eq!(
+    Date::try_from_ad(1868, 10, 22),
+    Err(DateError::BeforeMeiji {
+      year: 1868,
+      month: 10,
+      day: 22
+    })
+  );
+  assert_eq!(
+    Date::try_from_ad(2023, 13, 1),
+    Err(DateError::InvalidMonth(13))
+  );
+  assert_eq!(
+    Date::try_from_ad(2023, 2, 29),
+    Err(DateError::InvalidDay { month: 2, day: 29 })
+  );
+  assert!(Date::try_from_ad(2024, 2, 29).is_ok());
+  assert!(Date::try_from_ad(1900, 2, 29).is_err());
+  assert!(Date::try_from_ad(2000, 2, 29).is_ok());
+}
+
+#[test]
+fn check_date_try_new() {
+  assert!(Date::try_new(Era::Heisei, 1, None, None).is_ok());
+  assert!(Date::try_new(Era::Showa, 64, Some(1), None).is_ok());
+  assert!(Date::try_new(Era::Showa, 64, Some(2), None).is_err());
+  assert!(Date::try_new(Era::Showa, 65, None, None).is_err());
+  assert!(Date::try_new(Era::Heisei, 1, Some(1), Some(7)).is_err());
+  assert!(Date::try_new(Era::Taisho, 1, Some(7), None).is_ok());
+  assert!(Date::try_new(Era::Taisho, 1, Some(6), None).is_err());
+  assert!(Date::try_new(Era::Meiji, 1, Some(3), Some(14)).is_ok());
+  assert!(Date::try_new(Era::Meiji, 5, Some(12), Some(3)).is_err());
+  assert!(Date::try_new(Era::Reiwa, 5, Some(4), Some(31)).is_err());
+  assert_eq!(
+    Date::try_new(Era::Reiwa, 5, None, Some(1)),
+    Err(DateError::DayWithoutMonth)
+  );
+  assert_eq!(
+    Date::try_new(Era::Reiwa, 0, None, None),
+    Err(DateError::InvalidYear(0))
+  );
+}
+
 impl PartialOrd for Date {
   fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
     Some(self.cmp(other))
   }
 }
 
+/// 西暦の年・月・日の順で比較する
+/// 月や日が無い日付は、同じ年（月）の月日がある日付よりも前に並ぶ
+/// 西暦で同じ日付になる場合（昭和64年と平成元年など）は元号の順で比較する
 impl Ord for Date {
   fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-    let s = self.get_ad() * 10000 + self.month.unwrap_or(0) * 100 + self.day.unwrap_or(0);
-    let o = other.get_ad() * 10000 + other.month.unwrap_or(0) * 100 + other.day.unwrap_or(0);
-    s.cmp(&o)
+    (self.get_ad(), self.month, self.day, era_index(&self.era)).cmp(&(
+      other.get_ad(),
+      other.month,
+      other.day,
+      era_index(&other.era),
+    ))
+  }
+}
+
+#[test]
+fn check_date_ord() {
+  let showa = Date::new(Era::Showa, 64, None, None);
+  let heisei = Date::new(Era::Heisei, 1, None, None);
+  assert!(showa < heisei);
+  assert!(Date::new(Era::Heisei, 1, Some(1), None) > heisei);
+  assert!(Date::new(Era::Heisei, 1, Some(1), Some(8)) > Date::new(Era::Heisei, 1, Some(1), None));
+  assert!(
+    Date::new(Era::Showa, 64, Some(1), Some(7)) < Date::new(Era::Heisei, 1, Some(1), Some(8))
+  );
+}
+
+/// 元号名
+pub(crate) fn era_name(era: &Era) -> &'static str {
+  match era {
+    Era::Meiji => "明治",
+    Era::Taisho => "大正",
+    Era::Showa => "昭和",
+    Era::Heisei => "平成",
+    Era::Reiwa => "令和",
+  }
+}
+
+/// 文字列の先頭にある元号名を読み、残りの文字列と組にして返す
+pub(crate) fn split_era_prefix(s: &str) -> Option<(Era, &str)> {
+  [Era::Meiji, Era::Taisho, Era::Showa, Era::Heisei, Era::Reiwa]
+    .into_iter()
+    .find_map(|era| s.strip_prefix(era_name(&era)).map(|rest| (era, rest)))
+}
+
+/// 「令和五年六月十六日」のような和暦の表記で出力する
+/// 1年は「元年」と表記する
+impl Display for Date {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", era_name(&self.era))?;
+    if self.year == 1 {
+      write!(f, "元年")?;
+    } else {
+      write!(f, "{}年", to_kansuji(self.year))?;
+    }
+    if let Some(month) = self.month {
+      write!(f, "{}月", to_kansuji(month))?;
+    }
+    if let Some(day) = self.day {
+      write!(f, "{}日", to_kansuji(day))?;
+    }
+    Ok(())
+  }
+}
+
+/// 「令和五年六月十六日」「平成元年」「昭和２５年１０月」のような和暦の表記を読む
+/// 漢数字・全角数字・算用数字と元年に対応し、空白は無視する
+impl FromStr for Date {
+  type Err = DateError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || DateError::InvalidFormat(s.to_string());
+    let text = s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    let (era, rest) = split_era_prefix(&text).ok_or_else(invalid)?;
+    let (year_s, rest) = rest.split_once('年').ok_or_else(invalid)?;
+    let year = if year_s == "元" {
+      1
+    } else {
+      parse_number_ja(year_s).ok_or_else(invalid)?
+    };
+    let (month, rest) = match rest.split_once('月') {
+      Some((month_s, rest)) => (Some(parse_number_ja(month_s).ok_or_else(invalid)?), rest),
+      None => (None, rest),
+    };
+    let day = match rest.strip_suffix('日') {
+      Some(day_s) if month.is_some() => Some(parse_number_ja(day_s).ok_or_else(invalid)?),
+      _ if rest.is_empty() => None,
+      _ => return Err(invalid()),
+    };
+    Date::try_new(era, year, month, day)
+  }
+}
+
+impl Date {
+  /// ISO 8601形式（`2023-06-16`）で出力する
+  /// 月や日が無い場合は`2023-06`や`2023`のように省略する
+  pub fn to_iso8601(&self) -> String {
+    let mut s = format!("{:04}", self.get_ad());
+    if let Some(month) = self.month {
+      s.push_str(&format!("-{month:02}"));
+      if let Some(day) = self.day {
+        s.push_str(&format!("-{day:02}"));
+      }
+    }
+    s
+  }
+}
+
+#[test]
+fn check_date_from_str() {
+  assert_eq!(
+    Date::from_str("令和五年六月十六日"),
+    Ok(Date::new(Era::Reiwa, 5, Some(6), Some(16)))
+  );
+  assert_eq!(
+    Date::from_str("平成元年"),
+    Ok(Date::new(Era::Heisei, 1, None, None))
+  );
+  assert_eq!(
+    Date::from_str("昭和２５年１０月１日"),
+    Ok(Date::new(Era::Showa, 25, Some(10), Some(1)))
+  );
+  assert_eq!(
+    Date::from_str("令和5年 6月"),
+    Ok(Date::new(Era::Reiwa, 5, Some(6), None))
+  );
+  assert_eq!(
+    Date::from_str("昭和六十四年二月"),
+    Err(DateError::OutOfEra {
+      era: Era::Showa,
+      year: 64,
+      month: Some(2),
+      day: None
+    })
+  );
+  assert!(Date::from_str("令和五年十六日").is_err());
+  assert!(Date::from_str("令和五年六月十六日法律").is_err());
+  assert!(Date::from_str("西暦2023年").is_err());
+}
+
+#[test]
+fn check_date_display() {
+  let d = Date::new(Era::Reiwa, 5, Some(6), Some(16));
+  assert_eq!(d.to_string(), "令和五年六月十六日");
+  assert_eq!(d.to_iso8601(), "2023-06-16");
+  let d = Date::new(Era::Heisei, 1, Some(1), None);
+  assert_eq!(d.to_string(), "平成元年一月");
+  assert_eq!(d.to_iso8601(), "1989-01");
+  assert_eq!(Date::new(Era::Showa, 25, None, None).to_iso8601(), "1950");
+  for s in ["令和元年五月一日", "昭和二十五年", "大正十二年六月二十日"] {
+    assert_eq!(Date::from_str(s).unwrap().to_string(), s);
+  }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&Date> for chrono::NaiveDate {
+  type Error = DateError;
+  fn try_from(date: &Date) -> Result<Self, Self::Error> {
+    date.validate()?;
+    let (month, day) = match (date.month, date.day) {
+      (Some(month), Some(day)) => (month, day),
+      _ => return Err(DateError::Incomplete),
+    };
+    if date.get_ad() < 1873 {
+      return Err(DateError::Lunisolar);
+    }
+    chrono::NaiveDate::from_ymd_opt(date.get_ad() as i32, month as u32, day as u32)
+      .ok_or(DateError::InvalidDay { month, day })
+  }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for Date {
+  type Error = DateError;
+  fn try_from(date: chrono::NaiveDate) -> Result<Self, Self::Error> {
+    use chrono::Datelike;
+    let year = usize::try_from(date.year()).map_err(|_| DateError::BeforeMeiji {
+      year: 0,
+      month: date.month() as usize,
+      day: date.day() as usize,
+    })?;
+    Date::try_from_ad(year, date.month() as usize, date.day() as usize)
   }
 }
 
+#[cfg(feature = "chrono")]
+#[test]
+fn check_date_chrono() {
+  let d = Date::new(Era::Reiwa, 5, Some(6), Some(16));
+  let naive = chrono::NaiveDate::try_from(&d).unwrap();
+  assert_eq!(naive, chrono::NaiveDate::from_ymd_opt(2023, 6, 16).unwrap());
+  assert_eq!(Date::try_from(naive), Ok(d));
+  assert_eq!(
+    chrono::NaiveDate::try_from(&Date::new(Era::Reiwa, 5, None, None)),
+    Err(DateError::Incomplete)
+  );
+  assert_eq!(
+    chrono::NaiveDate::try_from(&Date::new(Era::Meiji, 3, Some(5), Some(1))),
+    Err(DateError::Lunisolar)
+  );
+}
+
 /// 法律の立法の種類
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum RippouType {
   /// 閣法
   Kakuhou,
@@ -103,7 +531,7 @@ pub enum RippouType {
 }
 
 /// 法律の効力の種類
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LawEfficacy {
   /// 政令
   CabinetOrder,
@@ -111,14 +539,46 @@ pub enum LawEfficacy {
   Law,
 }
 
+mod sealed {
+  pub trait Sealed {}
+}
+
 /// 府・省に共通化させる
-trait MinistryContents: Sized {
+/// 法令IDでは府省の組み合わせを`to_int`の番号をビット位置とするビット列で表す
+/// ビット位置が1〜28に収まるように、このクレートの外では実装できない
+pub trait MinistryContents: sealed::Sealed + Sized {
+  /// ビット位置（1〜28）
   fn to_int(&self) -> usize;
+  /// ビット位置から得る
   fn from_int(n: usize) -> Option<Self>;
+  /// 「経済産業省令」のような法形式の名前
+  fn name_ja(&self) -> &'static str;
+  /// 法形式の名前から得る
+  /// 同じ名前が複数ある場合は番号の小さい方になる
+  fn from_name_ja(name: &str) -> Option<Self> {
+    find_by_name_ja(name)
+  }
+}
+
+fn find_by_name_ja<T: MinistryContents>(name: &str) -> Option<T> {
+  (1..=28)
+    .filter_map(T::from_int)
+    .find(|t| t.name_ja() == name)
+}
+
+/// 府省名や機関名の解析時のエラー
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MinistryNameError {
+  /// 府省の区分の期間外の日付
+  #[error("no ministry period for {0}")]
+  OutOfPeriod(Date),
+  /// 知らない名前
+  #[error("unknown ministry name: {0}")]
+  UnknownName(String),
 }
 
 /// M1時（1869年7月8日〜1943年10月31日）での府・省
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum M1Ministry {
   /// 閣令
   CabinetOrder,
@@ -164,6 +624,8 @@ pub enum M1Ministry {
   MinistryOfJusticeOrdinanceHei,
 }
 
+impl sealed::Sealed for M1Ministry {}
+
 impl MinistryContents for M1Ministry {
   fn to_int(&self) -> usize {
     use M1Ministry::*;
@@ -219,10 +681,50 @@ impl MinistryContents for M1Ministry {
       _ => None,
     }
   }
+
+  fn name_ja(&self) -> &'static str {
+    use M1Ministry::*;
+    match self {
+      CabinetOrder => "閣令",
+      ImperialHouseholdOrdinance => "宮内省令",
+      GreaterEastAsiaMinisterialOrdinance => "大東亜省令",
+      MinistryOfTheInteriorOrdinance => "内務省令",
+      MinistryOfJusticeOrdinance => "司法省令",
+      MinistryOfForeignAffairsOrdinance => "外務省令",
+      MinistryOfFinanceOrdinance => "大蔵省令",
+      MinistryOfEducationOrdinance => "文部省令",
+      MinistryOfHealthAndWelfareOrdinance => "厚生省令",
+      MinistryOfAgricultureAndCommerceOrdinance => "農商務省令",
+      MinistryOfCommerceAndIndustryOrdinance => "商工省令",
+      RailwayMinisterialOrdinance => "鉄道省令",
+      MinistryOfCommunicationsOrdinance => "逓信省令",
+      MinistryOfTheArmyOrdinanceA => "陸軍省令",
+      NavyMinisterialOrdinance => "海軍省令",
+      MinistryOfTheArmyOrdinanceB => "陸軍省令",
+      MinistryOfAgricultureAndForestryOrdinance => "農林省令",
+      MinistryOfLandDevelopmentOrdinanceA => "拓殖務省令",
+      MinistryOfLandDevelopmentOrdinanceB => "拓務省令",
+      MinistryOfAgricultureAndCommerceOrdinanceTemporary => "農商務省令",
+      MinistryOfJusticeOrdinanceHei => "司法省令",
+    }
+  }
+}
+
+impl Display for M1Ministry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+impl FromStr for M1Ministry {
+  type Err = MinistryNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name_ja(s).ok_or_else(|| MinistryNameError::UnknownName(s.to_string()))
+  }
 }
 
 /// M2時（1943年11月1日〜1945年11月31日）での府・省
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum M2Ministry {
   /// 閣令
   CabinetOrder,
@@ -242,7 +744,7 @@ pub enum M2Ministry {
   MinistryOfEducationOrdinance,
   /// 厚生省令
   MinistryOfHealthAndWelfareOrdinance,
-  /// 農商務省令
+  /// 農商省令
   MinistryOfAgricultureAndCommerceOrdinance,
   /// 商工省令
   MinistryOfCommerceAndIndustryOrdinance,
@@ -260,6 +762,8 @@ pub enum M2Ministry {
   MinistryOfAgricultureAndForestryOrdinance,
 }
 
+impl sealed::Sealed for M2Ministry {}
+
 impl MinistryContents for M2Ministry {
   fn to_int(&self) -> usize {
     use M2Ministry::*;
@@ -307,10 +811,46 @@ impl MinistryContents for M2Ministry {
       _ => None,
     }
   }
+
+  fn name_ja(&self) -> &'static str {
+    use M2Ministry::*;
+    match self {
+      CabinetOrder => "閣令",
+      ImperialHouseholdOrdinance => "宮内省令",
+      GreaterEastAsiaMinisterialOrdinance => "大東亜省令",
+      MinistryOfTheInteriorOrdinance => "内務省令",
+      MinistryOfJusticeOrdinance => "司法省令",
+      MinistryOfForeignAffairsOrdinance => "外務省令",
+      MinistryOfFinanceOrdinance => "大蔵省令",
+      MinistryOfEducationOrdinance => "文部省令",
+      MinistryOfHealthAndWelfareOrdinance => "厚生省令",
+      MinistryOfAgricultureAndCommerceOrdinance => "農商省令",
+      MinistryOfCommerceAndIndustryOrdinance => "商工省令",
+      MinistryOfTransportOrdinance => "運輸省令",
+      MinistryOfTransportAndCommunicationsOrdinance => "運輸通信省令",
+      MinistryOfTheArmyOrdinanceA => "陸軍省令",
+      NavyMinisterialOrdinance => "海軍省令",
+      OrdinanceOfTheMinistryOfMunitions => "軍需省令",
+      MinistryOfAgricultureAndForestryOrdinance => "農林省令",
+    }
+  }
+}
+
+impl Display for M2Ministry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+impl FromStr for M2Ministry {
+  type Err = MinistryNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name_ja(s).ok_or_else(|| MinistryNameError::UnknownName(s.to_string()))
+  }
 }
 
 /// M3時（1945年12月1日〜1947年5月2日）での府・省
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum M3Ministry {
   /// 閣令
   CabinetOrder,
@@ -348,6 +888,8 @@ pub enum M3Ministry {
   CentralLaborRelationsCommissionRules,
 }
 
+impl sealed::Sealed for M3Ministry {}
+
 impl MinistryContents for M3Ministry {
   fn to_int(&self) -> usize {
     use M3Ministry::*;
@@ -395,10 +937,46 @@ impl MinistryContents for M3Ministry {
       _ => None,
     }
   }
+
+  fn name_ja(&self) -> &'static str {
+    use M3Ministry::*;
+    match self {
+      CabinetOrder => "閣令",
+      ImperialHouseholdOrdinance => "宮内省令",
+      EconomicStabilityHeadquartersOrdinance => "経済安定本部令",
+      MinistryOfTheInteriorOrdinance => "内務省令",
+      MinistryOfJusticeOrdinance => "司法省令",
+      MinistryOfForeignAffairsOrdinance => "外務省令",
+      MinistryOfFinanceOrdinance => "大蔵省令",
+      MinistryOfEducationOrdinance => "文部省令",
+      MinistryOfHealthAndWelfareOrdinance => "厚生省令",
+      MinistryOfAgricultureAndForestryOrdinance => "農林省令",
+      MinistryOfCommerceAndIndustryOrdinance => "商工省令",
+      MinistryOfTransportOrdinance => "運輸省令",
+      MinistryOfCommunicationsOrdinance => "逓信省令",
+      FirstMinisterialOrdinanceForDemobilization => "第一復員省令",
+      SecondMinisterialOrdinanceForDemobilization => "第二復員省令",
+      PriceAgencyOrdinance => "物価庁令",
+      CentralLaborRelationsCommissionRules => "中央労働委員会規則",
+    }
+  }
+}
+
+impl Display for M3Ministry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+impl FromStr for M3Ministry {
+  type Err = MinistryNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name_ja(s).ok_or_else(|| MinistryNameError::UnknownName(s.to_string()))
+  }
 }
 
 /// M4時（1947年5月3日〜1949年5月31日）での府・省
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum M4Ministry {
   /// 法務庁令
   LegalAffairsAgencyOrdinance,
@@ -442,6 +1020,8 @@ pub enum M4Ministry {
   NationalPublicSafetyCommissionRegulations,
 }
 
+impl sealed::Sealed for M4Ministry {}
+
 impl MinistryContents for M4Ministry {
   fn to_int(&self) -> usize {
     use M4Ministry::*;
@@ -495,14 +1075,53 @@ impl MinistryContents for M4Ministry {
       _ => None,
     }
   }
+
+  fn name_ja(&self) -> &'static str {
+    use M4Ministry::*;
+    match self {
+      LegalAffairsAgencyOrdinance => "法務庁令",
+      PrimeMinistersOfficeOrdinance => "総理庁令",
+      EconomicStabilityHeadquartersOrdinance => "経済安定本部令",
+      MinistryOfTheInteriorOrdinance => "内務省令",
+      MinistryOfJusticeOrdinance => "司法省令",
+      MinistryOfForeignAffairsOrdinance => "外務省令",
+      MinistryOfFinanceOrdinance => "大蔵省令",
+      MinistryOfEducationOrdinance => "文部省令",
+      MinistryOfHealthAndWelfareOrdinance => "厚生省令",
+      MinistryOfAgricultureAndForestryOrdinance => "農林省令",
+      MinistryOfInternationalTradeAndIndustryOrdinance => "通商産業省令",
+      MinistryOfTransportOrdinance => "運輸省令",
+      MinistryOfCommunicationsOrdinance => "逓信省令",
+      MinistryOfLaborOrdinance => "労働省令",
+      MinistryOfConstructionOrdinance => "建設省令",
+      PriceAgencyOrdinance => "物価庁令",
+      MinistryOfCommerceAndIndustryOrdinance => "商工省令",
+      CentralLaborRelationsCommissionRules => "中央労働委員会規則",
+      FairTradeCommissionRules => "公正取引委員会規則",
+      NationalPublicSafetyCommissionRegulations => "国家公安委員会規則",
+    }
+  }
+}
+
+impl Display for M4Ministry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+impl FromStr for M4Ministry {
+  type Err = MinistryNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name_ja(s).ok_or_else(|| MinistryNameError::UnknownName(s.to_string()))
+  }
 }
 
 /// M5時（1949年6月1日〜2001年1月15日）での府・省
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum M5Ministry {
-  /// 法務庁令
+  /// 法務府令
   LegalAffairsAgencyOrdinance,
-  /// 総理庁令
+  /// 総理府令
   PrimeMinistersOfficeOrdinance,
   /// 経済安定本部令
   EconomicStabilityHeadquartersOrdinance,
@@ -552,6 +1171,8 @@ pub enum M5Ministry {
   PublicSafetyReviewCommitteeRules,
 }
 
+impl sealed::Sealed for M5Ministry {}
+
 impl MinistryContents for M5Ministry {
   fn to_int(&self) -> usize {
     use M5Ministry::*;
@@ -615,18 +1236,64 @@ impl MinistryContents for M5Ministry {
       _ => None,
     }
   }
+
+  fn name_ja(&self) -> &'static str {
+    use M5Ministry::*;
+    match self {
+      LegalAffairsAgencyOrdinance => "法務府令",
+      PrimeMinistersOfficeOrdinance => "総理府令",
+      EconomicStabilityHeadquartersOrdinance => "経済安定本部令",
+      MinistryOfHomeAffairsOrdinance => "自治省令",
+      MinistryOfJusticeOrdinance => "法務省令",
+      MinistryOfForeignAffairsOrdinance => "外務省令",
+      MinistryOfFinanceOrdinance => "大蔵省令",
+      MinistryOfEducationOrdinance => "文部省令",
+      MinistryOfHealthAndWelfareOrdinance => "厚生省令",
+      MinistryOfAgricultureAndForestryAndFisheriesOrdinance => "農林水産省令",
+      MinistryOfInternationalTradeAndIndustryOrdinance => "通商産業省令",
+      MinistryOfTransportOrdinance => "運輸省令",
+      MinistryOfPostsAndTelecommunicationsOrdinance => "郵政省令",
+      MinistryOfLaborOrdinance => "労働省令",
+      MinistryOfConstructionOrdinance => "建設省令",
+      PriceAgencyOrdinance => "物価庁令",
+      MinistryOfAgricultureAndForestryOrdinance => "農林省令",
+      TelecommunicationsMinisterialOrdinance => "電気通信省令",
+      CentralMinistriesAndAgenciesReformPromotionHeadquartersOrdinance => {
+        "中央省庁等改革推進本部令"
+      }
+      RadioRegulatoryCommissionRules => "電波監理委員会規則",
+      CentralLaborRelationsCommissionRules => "中央労働委員会規則",
+      FairTradeCommissionRules => "公正取引委員会規則",
+      NationalPublicSafetyCommissionRegulations => "国家公安委員会規則",
+      PollutionAdjustmentCommitteeRules => "公害等調整委員会規則",
+      PublicSafetyReviewCommitteeRules => "公安審査委員会規則",
+    }
+  }
+}
+
+impl Display for M5Ministry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+impl FromStr for M5Ministry {
+  type Err = MinistryNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name_ja(s).ok_or_else(|| MinistryNameError::UnknownName(s.to_string()))
+  }
 }
 
 /// M6時（2001年1月16日〜）での府・省
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum M6Ministry {
   /// 内閣官房令
   CabinetSecretariatOrdinance,
-  /// 総理庁令
+  /// 内閣府令
   PrimeMinistersOfficeOrdinance,
   /// 復興庁令
   ReconstructionAgencyOrdinance,
-  /// 自治省令
+  /// 総務省令
   MinistryOfHomeAffairsOrdinance,
   /// 法務省令
   MinistryOfJusticeOrdinance,
@@ -650,7 +1317,7 @@ pub enum M6Ministry {
   MinistryOfDefenseOrdinance,
   /// デジタル庁令
   DigitalAgencyOrdinance,
-  /// 特定個人情報保護委員会規則
+  /// 個人情報保護委員会規則（旧特定個人情報保護委員会規則）
   SpecificPersonalInformationProtectionCommissionRules,
   /// 運輸安全委員会規則
   JapanTransportSafetyBoardRegulations,
@@ -670,6 +1337,8 @@ pub enum M6Ministry {
   CasinoManagementCommitteeRules,
 }
 
+impl sealed::Sealed for M6Ministry {}
+
 impl MinistryContents for M6Ministry {
   fn to_int(&self) -> usize {
     use M6Ministry::*;
@@ -731,14 +1400,66 @@ impl MinistryContents for M6Ministry {
       _ => None,
     }
   }
+
+  fn name_ja(&self) -> &'static str {
+    use M6Ministry::*;
+    match self {
+      CabinetSecretariatOrdinance => "内閣官房令",
+      PrimeMinistersOfficeOrdinance => "内閣府令",
+      ReconstructionAgencyOrdinance => "復興庁令",
+      MinistryOfHomeAffairsOrdinance => "総務省令",
+      MinistryOfJusticeOrdinance => "法務省令",
+      MinistryOfForeignAffairsOrdinance => "外務省令",
+      MinistryOfFinanceOrdinance => "財務省令",
+      MinistryOfEducationAndCultureAndSportsAndScienceAndTechnologyOrdinance => "文部科学省令",
+      MinistryOfHealthAndLaborAndWelfareOrdinance => "厚生労働省令",
+      MinistryOfAgricultureAndForestryAndFisheriesOrdinance => "農林水産省令",
+      MinistryOfEconomyAndTradeAndIndustryOrdinance => "経済産業省令",
+      MinistryOfLandAndInfrastructureAndTransportAndTourismOrdinance => "国土交通省令",
+      MinistryOfTheEnvironmentOrdinance => "環境省令",
+      MinistryOfDefenseOrdinance => "防衛省令",
+      DigitalAgencyOrdinance => "デジタル庁令",
+      SpecificPersonalInformationProtectionCommissionRules => "個人情報保護委員会規則",
+      JapanTransportSafetyBoardRegulations => "運輸安全委員会規則",
+      NuclearRegulationAuthorityRegulations => "原子力規制委員会規則",
+      CentralLaborRelationsCommissionRules => "中央労働委員会規則",
+      FairTradeCommissionRules => "公正取引委員会規則",
+      NationalPublicSafetyCommissionRegulations => "国家公安委員会規則",
+      PollutionAdjustmentCommitteeRules => "公害等調整委員会規則",
+      PublicSafetyReviewCommitteeRules => "公安審査委員会規則",
+      CasinoManagementCommitteeRules => "カジノ管理委員会規則",
+    }
+  }
+
+  fn from_name_ja(name: &str) -> Option<Self> {
+    match name {
+      "特定個人情報保護委員会規則" => {
+        Some(M6Ministry::SpecificPersonalInformationProtectionCommissionRules)
+      }
+      _ => find_by_name_ja(name),
+    }
+  }
+}
+
+impl Display for M6Ministry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+impl FromStr for M6Ministry {
+  type Err = MinistryNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name_ja(s).ok_or_else(|| MinistryNameError::UnknownName(s.to_string()))
+  }
 }
 
 /// 府・省
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Ministry {
   /// 1869年7月8日〜1943年10月31日
   M1(Vec<M1Ministry>),
-  /// 1943年11月1日〜1945年11月31日
+  /// 1943年11月1日〜1945年11月30日
   M2(Vec<M2Ministry>),
   /// 1945年12月1日〜1947年5月2日
   M3(Vec<M3Ministry>),
@@ -750,7 +1471,8 @@ pub enum Ministry {
   M6(Vec<M6Ministry>),
 }
 
-fn ministry_list_to_usize<T: MinistryContents>(l: &[T]) -> usize {
+/// 府省のリストから法令IDで使うビット列を得る
+pub fn ministry_list_to_usize<T: MinistryContents>(l: &[T]) -> usize {
   let mut n = 0;
   for u in l.iter().map(|v| v.to_int() as u32) {
     n |= 2_u32.pow(u - 1);
@@ -758,34 +1480,448 @@ fn ministry_list_to_usize<T: MinistryContents>(l: &[T]) -> usize {
   n as usize
 }
 
-#[test]
-fn check_ministry_list_to_usize() {
-  let m = vec![M6Ministry::MinistryOfEconomyAndTradeAndIndustryOrdinance];
-  let n = ministry_list_to_usize(&m);
-  assert_eq!(n, 0x400);
+/// 府省の区分
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MinistryPeriod {
+  /// 1869年7月8日〜1943年10月31日
+  M1,
+  /// 1943年11月1日〜1945年11月30日
+  M2,
+  /// 1945年12月1日〜1947年5月2日
+  M3,
+  /// 1947年5月3日〜1949年5月31日
+  M4,
+  /// 1949年6月1日〜2001年1月15日
+  M5,
+  /// 2001年1月16日〜
+  M6,
 }
 
-fn list_from_str<T: MinistryContents + std::fmt::Debug>(byte_s: &str) -> Result<Vec<T>, ()> {
-  let chars = byte_s.chars();
-  let mut v = Vec::new();
-  for (i, c) in chars.enumerate() {
+impl MinistryPeriod {
+  /// 全ての区分（古い順）
+  pub const ALL: [MinistryPeriod; 6] = [
+    MinistryPeriod::M1,
+    MinistryPeriod::M2,
+    MinistryPeriod::M3,
+    MinistryPeriod::M4,
+    MinistryPeriod::M5,
+    MinistryPeriod::M6,
+  ];
+
+  /// 法令IDの「M1」〜「M6」の数字
+  pub fn number(&self) -> usize {
+    *self as usize + 1
+  }
+
+  fn ad_range(&self) -> (AdDate, Option<AdDate>) {
+    match self {
+      MinistryPeriod::M1 => ((1869, 7, 8), Some((1943, 10, 31))),
+      MinistryPeriod::M2 => ((1943, 11, 1), Some((1945, 11, 30))),
+      MinistryPeriod::M3 => ((1945, 12, 1), Some((1947, 5, 2))),
+      MinistryPeriod::M4 => ((1947, 5, 3), Some((1949, 5, 31))),
+      MinistryPeriod::M5 => ((1949, 6, 1), Some((2001, 1, 15))),
+      MinistryPeriod::M6 => ((2001, 1, 16), None),
+    }
+  }
+
+  /// 区分の初日
+  pub fn start(&self) -> Date {
+    Date::from_ad_unchecked(self.ad_range().0)
+  }
+
+  /// 区分の最終日
+  /// 現行の区分では`None`になる
+  pub fn end(&self) -> Option<Date> {
+    self.ad_range().1.map(Date::from_ad_unchecked)
+  }
+
+  /// 日付が区分の期間と重なるかを判定する
+  /// 年のみの日付などでは一部でも重なれば`true`になる
+  pub fn overlaps(&self, date: &Date) -> bool {
+    let (first, last) = date.ad_range();
+    let (start, end) = self.ad_range();
+    start <= last && end.map_or(true, |end| first <= end)
+  }
+}
+
+#[test]
+fn check_ministry_period() {
+  assert_eq!(MinistryPeriod::M2.number(), 2);
+  assert_eq!(
+    MinistryPeriod::M2.end(),
+    Some(Date::new(Era::Showa, 20, Some(11), Some(30)))
+  );
+  assert_eq!(MinistryPeriod::M6.end(), None);
+  let d = Date::new(Era::Showa, 20, None, None);
+  assert!(MinistryPeriod::M2.overlaps(&d));
+  assert!(MinistryPeriod::M3.overlaps(&d));
+  assert!(!MinistryPeriod::M4.overlaps(&d));
+}
+
+/// 府省の区分ごとの府省
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MinistryMember {
+  /// 区分M1の府省
+  M1(M1Ministry),
+  /// 区分M2の府省
+  M2(M2Ministry),
+  /// 区分M3の府省
+  M3(M3Ministry),
+  /// 区分M4の府省
+  M4(M4Ministry),
+  /// 区分M5の府省
+  M5(M5Ministry),
+  /// 区分M6の府省
+  M6(M6Ministry),
+}
+
+impl MinistryMember {
+  /// 「経済産業省令」のような法形式の名前
+  pub fn name_ja(&self) -> &'static str {
+    match self {
+      MinistryMember::M1(m) => m.name_ja(),
+      MinistryMember::M2(m) => m.name_ja(),
+      MinistryMember::M3(m) => m.name_ja(),
+      MinistryMember::M4(m) => m.name_ja(),
+      MinistryMember::M5(m) => m.name_ja(),
+      MinistryMember::M6(m) => m.name_ja(),
+    }
+  }
+
+  /// 所属する区分
+  pub fn period(&self) -> MinistryPeriod {
+    match self {
+      MinistryMember::M1(_) => MinistryPeriod::M1,
+      MinistryMember::M2(_) => MinistryPeriod::M2,
+      MinistryMember::M3(_) => MinistryPeriod::M3,
+      MinistryMember::M4(_) => MinistryPeriod::M4,
+      MinistryMember::M5(_) => MinistryPeriod::M5,
+      MinistryMember::M6(_) => MinistryPeriod::M6,
+    }
+  }
+}
+
+impl Display for MinistryMember {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+impl From<M1Ministry> for MinistryMember {
+  fn from(m: M1Ministry) -> Self {
+    MinistryMember::M1(m)
+  }
+}
+
+impl From<M2Ministry> for MinistryMember {
+  fn from(m: M2Ministry) -> Self {
+    MinistryMember::M2(m)
+  }
+}
+
+impl From<M3Ministry> for MinistryMember {
+  fn from(m: M3Ministry) -> Self {
+    MinistryMember::M3(m)
+  }
+}
+
+impl From<M4Ministry> for MinistryMember {
+  fn from(m: M4Ministry) -> Self {
+    MinistryMember::M4(m)
+  }
+}
+
+impl From<M5Ministry> for MinistryMember {
+  fn from(m: M5Ministry) -> Self {
+    MinistryMember::M5(m)
+  }
+}
+
+impl From<M6Ministry> for MinistryMember {
+  fn from(m: M6Ministry) -> Self {
+    MinistryMember::M6(m)
+  }
+}
+
+fn names_to_list<T: MinistryContents, S: AsRef<str>>(names: &[S]) -> Option<Vec<T>> {
+  names
+    .iter()
+    .map(|name| T::from_name_ja(name.as_ref()))
+    .collect()
+}
+
+impl Ministry {
+  /// 構成する府省の法形式の名前のリスト
+  /// 法令番号での慣例に合わせて番号の小さい順に並べる
+  pub fn names_ja(&self) -> Vec<&'static str> {
+    fn f<T: MinistryContents>(l: &[T]) -> Vec<&'static str> {
+      let mut l = l.iter().collect::<Vec<_>>();
+      l.sort_by_key(|t| t.to_int());
+      l.into_iter().map(|t| t.name_ja()).collect()
+    }
+    match self {
+      Ministry::M1(l) => f(l),
+      Ministry::M2(l) => f(l),
+      Ministry::M3(l) => f(l),
+      Ministry::M4(l) => f(l),
+      Ministry::M5(l) => f(l),
+      Ministry::M6(l) => f(l),
+    }
+  }
+
+  /// 府省のビット列
+  fn to_bits(&self) -> usize {
+    match self {
+      Ministry::M1(l) => ministry_list_to_usize(l),
+      Ministry::M2(l) => ministry_list_to_usize(l),
+      Ministry::M3(l) => ministry_list_to_usize(l),
+      Ministry::M4(l) => ministry_list_to_usize(l),
+      Ministry::M5(l) => ministry_list_to_usize(l),
+      Ministry::M6(l) => ministry_list_to_usize(l),
+    }
+  }
+
+  /// 府省の区分
+  pub fn period(&self) -> MinistryPeriod {
+    match self {
+      Ministry::M1(_) => MinistryPeriod::M1,
+      Ministry::M2(_) => MinistryPeriod::M2,
+      Ministry::M3(_) => MinistryPeriod::M3,
+      Ministry::M4(_) => MinistryPeriod::M4,
+      Ministry::M5(_) => MinistryPeriod::M5,
+      Ministry::M6(_) => MinistryPeriod::M6,
+    }
+  }
+
+  /// 日付が属する府省の区分を得る
+  /// 年のみの日付などで複数の区分にまたがる場合は、期間の初日が属する区分になる
+  pub fn period_for(date: &Date) -> Option<MinistryPeriod> {
+    let first = date.ad_range().0;
+    MinistryPeriod::ALL.into_iter().find(|period| {
+      let (start, end) = period.ad_range();
+      start <= first && end.map_or(true, |end| first <= end)
+    })
+  }
+
+  /// 日付と重なる府省の区分を全て得る
+  pub fn periods_for(date: &Date) -> Vec<MinistryPeriod> {
+    MinistryPeriod::ALL
+      .into_iter()
+      .filter(|period| period.overlaps(date))
+      .collect()
+  }
+
+  /// 構成する府省を順に返す
+  pub fn members(&self) -> impl Iterator<Item = MinistryMember> + '_ {
+    fn f<T: MinistryContents + Clone + Into<MinistryMember>>(
+      l: &[T],
+    ) -> Box<dyn Iterator<Item = MinistryMember> + '_> {
+      Box::new(l.iter().cloned().map(Into::into))
+    }
+    match self {
+      Ministry::M1(l) => f(l),
+      Ministry::M2(l) => f(l),
+      Ministry::M3(l) => f(l),
+      Ministry::M4(l) => f(l),
+      Ministry::M5(l) => f(l),
+      Ministry::M6(l) => f(l),
+    }
+  }
+
+  /// 府省が含まれているかを判定する
+  /// 区分が異なる場合は`false`になる
+  pub fn contains(&self, member: &MinistryMember) -> bool {
+    self.members().any(|m| &m == member)
+  }
+
+  /// 法令IDを解析した場合と同じ並びに揃える
+  fn canonicalize(&self) -> Self {
+    let bits = self.to_bits();
+    match self {
+      Ministry::M1(_) => Ministry::M1(list_from_bits(bits)),
+      Ministry::M2(_) => Ministry::M2(list_from_bits(bits)),
+      Ministry::M3(_) => Ministry::M3(list_from_bits(bits)),
+      Ministry::M4(_) => Ministry::M4(list_from_bits(bits)),
+      Ministry::M5(_) => Ministry::M5(list_from_bits(bits)),
+      Ministry::M6(_) => Ministry::M6(list_from_bits(bits)),
+    }
+  }
+
+  /// 指定した区分で名前のリストを解決する
+  pub fn from_names_in_period<S: AsRef<str>>(names: &[S], period: MinistryPeriod) -> Option<Self> {
+    match period {
+      MinistryPeriod::M1 => names_to_list(names).map(Ministry::M1),
+      MinistryPeriod::M2 => names_to_list(names).map(Ministry::M2),
+      MinistryPeriod::M3 => names_to_list(names).map(Ministry::M3),
+      MinistryPeriod::M4 => names_to_list(names).map(Ministry::M4),
+      MinistryPeriod::M5 => names_to_list(names).map(Ministry::M5),
+      MinistryPeriod::M6 => names_to_list(names).map(Ministry::M6),
+    }
+  }
+
+  /// `["経済産業省令", "環境省令"]`のような法形式の名前のリストから、日付に対応する区分の府省を得る
+  /// 年のみの日付などで複数の区分にまたがる場合は、全ての名前を解決できる最も古い区分を選ぶ
+  pub fn from_names<S: AsRef<str>>(names: &[S], date: &Date) -> Result<Self, MinistryNameError> {
+    let periods = Self::periods_for(date);
+    if periods.is_empty() {
+      return Err(MinistryNameError::OutOfPeriod(date.clone()));
+    }
+    periods
+      .into_iter()
+      .find_map(|period| Self::from_names_in_period(names, period))
+      .ok_or_else(|| MinistryNameError::UnknownName(join_issuers(names)))
+  }
+
+  /// 「経済産業省・環境省令」のような表記から、日付に対応する区分の府省を得る
+  pub fn from_str_with_date(s: &str, date: &Date) -> Result<Self, MinistryNameError> {
+    Self::from_names(&split_issuers(s), date)
+  }
+}
+
+/// 「経済産業省・環境省令」のように出力する
+impl Display for Ministry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", join_issuers(&self.names_ja()))
+  }
+}
+
+#[test]
+fn check_ministry_members() {
+  assert_eq!(
+    Ministry::period_for(&Date::new(Era::Reiwa, 5, Some(6), Some(16))),
+    Some(MinistryPeriod::M6)
+  );
+  assert_eq!(
+    Ministry::period_for(&Date::new(Era::Heisei, 13, Some(1), Some(15))),
+    Some(MinistryPeriod::M5)
+  );
+  assert_eq!(
+    Ministry::period_for(&Date::new(Era::Heisei, 13, None, None)),
+    Some(MinistryPeriod::M5)
+  );
+  assert_eq!(
+    Ministry::periods_for(&Date::new(Era::Heisei, 13, None, None)),
+    vec![MinistryPeriod::M5, MinistryPeriod::M6]
+  );
+  assert_eq!(
+    Ministry::period_for(&Date::new(Era::Meiji, 1, None, None)),
+    None
+  );
+  let ministry = Ministry::M6(vec![
+    M6Ministry::MinistryOfTheEnvironmentOrdinance,
+    M6Ministry::MinistryOfEconomyAndTradeAndIndustryOrdinance,
+  ]);
+  assert_eq!(ministry.period(), MinistryPeriod::M6);
+  assert!(ministry.contains(&M6Ministry::MinistryOfTheEnvironmentOrdinance.into()));
+  assert!(!ministry.contains(&M6Ministry::MinistryOfFinanceOrdinance.into()));
+  assert!(!ministry.contains(&M5Ministry::MinistryOfFinanceOrdinance.into()));
+  assert_eq!(
+    ministry
+      .members()
+      .map(|m| m.to_string())
+      .collect::<Vec<_>>(),
+    vec!["環境省令", "経済産業省令"]
+  );
+}
+
+#[test]
+fn check_ministry_name() {
+  let date = Date::new(Era::Reiwa, 5, None, None);
+  let ministry = Ministry::from_str_with_date("経済産業省・環境省令", &date).unwrap();
+  assert_eq!(
+    ministry,
+    Ministry::M6(vec![
+      M6Ministry::MinistryOfEconomyAndTradeAndIndustryOrdinance,
+      M6Ministry::MinistryOfTheEnvironmentOrdinance
+    ])
+  );
+  assert_eq!(ministry.to_string(), "経済産業省・環境省令");
+  assert_eq!(
+    Ministry::from_str_with_date("大蔵省令", &Date::new(Era::Showa, 25, None, None)),
+    Ok(Ministry::M5(vec![M5Ministry::MinistryOfFinanceOrdinance]))
+  );
+  // 昭和20年は区分M2とM3にまたがる
+  assert_eq!(
+    Ministry::from_str_with_date("軍需省令", &Date::new(Era::Showa, 20, None, None)),
+    Ok(Ministry::M2(vec![
+      M2Ministry::OrdinanceOfTheMinistryOfMunitions
+    ]))
+  );
+  assert_eq!(
+    Ministry::from_str_with_date("物価庁令", &Date::new(Era::Showa, 20, None, None)),
+    Ok(Ministry::M3(vec![M3Ministry::PriceAgencyOrdinance]))
+  );
+  assert_eq!(
+    Ministry::from_str_with_date("財務省令", &Date::new(Era::Showa, 25, None, None)),
+    Err(MinistryNameError::UnknownName("財務省令".to_string()))
+  );
+  assert!(matches!(
+    Ministry::from_str_with_date("閣令", &Date::new(Era::Meiji, 1, None, None)),
+    Err(MinistryNameError::OutOfPeriod(_))
+  ));
+  assert_eq!(
+    M6Ministry::from_str("特定個人情報保護委員会規則"),
+    Ok(M6Ministry::SpecificPersonalInformationProtectionCommissionRules)
+  );
+  assert_eq!(
+    M6Ministry::SpecificPersonalInformationProtectionCommissionRules.to_string(),
+    "個人情報保護委員会規則"
+  );
+  for n in 1..=28 {
+    if let Some(m) = M5Ministry::from_int(n) {
+      assert_eq!(M5Ministry::from_str(&m.to_string()), Ok(m));
+    }
+    if let Some(m) = M6Ministry::from_int(n) {
+      assert_eq!(M6Ministry::from_str(&m.to_string()), Ok(m));
+    }
+  }
+}
+
+#[test]
+fn check_ministry_list_to_usize() {
+  let m = vec![M6Ministry::MinistryOfEconomyAndTradeAndIndustryOrdinance];
+  let n = ministry_list_to_usize(&m);
+  assert_eq!(n, 0x400);
+}
+
+/// 2進数の文字列から府省のリストを得る
+/// エラーの位置は文字列の先頭からではなく0になるので、必要に応じて呼び出し側で補正する
+pub fn list_from_str<T: MinistryContents + std::fmt::Debug>(
+  byte_s: &str,
+) -> Result<Vec<T>, LawIdParseError> {
+  let chars = byte_s.chars();
+  let mut v = Vec::new();
+  for (i, c) in chars.enumerate() {
     if c == '1' {
-      let n = 28 - i;
+      let n = 28_usize.saturating_sub(i);
       if let Some(t) = T::from_int(n) {
         v.push(t);
       } else {
-        return Err(());
+        return Err(LawIdParseError::UnknownMinistryBit { offset: 0, bit: n });
       }
     } else if c == '0' {
     } else {
-      return Err(());
+      return Err(LawIdParseError::InvalidNumber {
+        offset: 0,
+        found: byte_s.to_string(),
+      });
     }
   }
   Ok(v)
 }
 
+/// ビット列から府省のリストを得る
+/// ビットは府省から作ったものなので、`list_from_str`と同じくビット位置の大きい順に並ぶ
+fn list_from_bits<T: MinistryContents>(bits: usize) -> Vec<T> {
+  (1..=28)
+    .rev()
+    .filter(|n| bits & (1 << (n - 1)) != 0)
+    .filter_map(T::from_int)
+    .collect()
+}
+
 /// 機関名
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Institution {
   /// 会計検査院
   BoardOfAudit,
@@ -819,7 +1955,9 @@ pub enum Institution {
   HouseOfCouncilors,
   /// 船員中央労働委員会
   SeafarersCentralLaborCommittee,
-  // /// 司法試験管理委員会
+  /// 司法試験管理委員会（法令IDのコード17）
+  /// 名前はコード8と同じで、法令IDのコードを保つために分けている
+  BarExaminationManagementCommitteeCode17,
   /// 電波監理委員会
   RadioRegulatoryCommission,
   /// カジノ管理委員会
@@ -846,6 +1984,7 @@ impl Institution {
       HouseOfRepresentatives => 14,
       HouseOfCouncilors => 15,
       SeafarersCentralLaborCommittee => 16,
+      BarExaminationManagementCommitteeCode17 => 17,
       RadioRegulatoryCommission => 18,
       CasinoManagementCommittee => 19,
     }
@@ -870,16 +2009,132 @@ impl Institution {
       14 => Some(HouseOfRepresentatives),
       15 => Some(HouseOfCouncilors),
       16 => Some(SeafarersCentralLaborCommittee),
-      17 => Some(BarExaminationManagementCommittee),
+      17 => Some(BarExaminationManagementCommitteeCode17),
       18 => Some(RadioRegulatoryCommission),
       19 => Some(CasinoManagementCommittee),
       _ => None,
     }
   }
+
+  /// 「会計検査院」のような機関の名前
+  /// コード8とコード17はどちらも「司法試験管理委員会」になる
+  pub fn institution_name(&self) -> &'static str {
+    use Institution::*;
+    match self {
+      BoardOfAudit => "会計検査院",
+      CoastGuard => "海上保安庁",
+      ScienceCouncilOfJapan => "日本学術会議",
+      LandAdjustmentCommittee => "土地調整委員会",
+      FinancialReconstructionCommittee => "金融再生委員会",
+      MetropolitanAreaDevelopmentCommittee => "首都圏整備委員会",
+      LocalFinanceCommittee => "地方財政委員会",
+      BarExaminationManagementCommittee | BarExaminationManagementCommitteeCode17 => {
+        "司法試験管理委員会"
+      }
+      CertifiedPublicAccountantManagementCommittee => "公認会計士管理委員会",
+      ForeignInvestmentCommittee => "外資委員会",
+      CulturalPropertiesProtectionCommittee => "文化財保護委員会",
+      JapaneseNationalCommissionForUNESCO => "日本ユネスコ国内委員会",
+      SupremeCourt => "最高裁判所",
+      HouseOfRepresentatives => "衆議院",
+      HouseOfCouncilors => "参議院",
+      SeafarersCentralLaborCommittee => "船員中央労働委員会",
+      RadioRegulatoryCommission => "電波監理委員会",
+      CasinoManagementCommittee => "カジノ管理委員会",
+    }
+  }
+
+  /// 「会計検査院規則」のような法形式の名前
+  /// [`Institution::institution_name`]に「規則」を付けたもの
+  pub fn name_ja(&self) -> String {
+    format!("{}規則", self.institution_name())
+  }
+}
+
+/// 「会計検査院規則」のように出力する
+impl Display for Institution {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.name_ja())
+  }
+}
+
+/// 「会計検査院規則」と「会計検査院」のどちらの形でも受け付ける
+/// 「司法試験管理委員会規則」はコード8になる
+impl FromStr for Institution {
+  type Err = MinistryNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let name = s.strip_suffix("規則").unwrap_or(s);
+    (1..=19)
+      .filter_map(Institution::from_int)
+      .find(|i| i.institution_name() == name)
+      .ok_or_else(|| MinistryNameError::UnknownName(s.to_string()))
+  }
+}
+
+#[test]
+fn check_institution_code() {
+  let mut institutions = Vec::new();
+  for code in 0..100 {
+    let Some(institution) = Institution::from_int(code) else {
+      assert!(code == 0 || code > 19, "missing institution code {code}");
+      continue;
+    };
+    assert_eq!(institution.to_int(), code);
+    assert!(!institutions.contains(&institution));
+    institutions.push(institution.clone());
+    let s = format!("322R{code:08}001");
+    let law_id = LawId::from_str(&s).unwrap();
+    assert_eq!(
+      law_id.law_id_type,
+      LawIdType::Regulation {
+        institution,
+        num: 1
+      }
+    );
+    assert_eq!(law_id.to_string(), s);
+  }
+  assert_eq!(institutions.len(), 19);
+  assert_eq!(
+    LawId::from_str("322R00000020001"),
+    Err(LawIdParseError::UnknownInstitution {
+      offset: 4,
+      code: 20
+    })
+  );
+}
+
+#[test]
+fn check_institution_name() {
+  assert_eq!(Institution::BoardOfAudit.name_ja(), "会計検査院規則");
+  assert_eq!(Institution::SupremeCourt.to_string(), "最高裁判所規則");
+  assert_eq!(
+    Institution::from_str("衆議院規則"),
+    Ok(Institution::HouseOfRepresentatives)
+  );
+  assert_eq!(
+    Institution::from_str("参議院"),
+    Ok(Institution::HouseOfCouncilors)
+  );
+  assert!(Institution::from_str("経済産業省令").is_err());
+  for institution in (1..=19).filter_map(Institution::from_int) {
+    assert_eq!(
+      Institution::from_str(&institution.name_ja()).map(|i| i.name_ja()),
+      Ok(institution.name_ja())
+    );
+    assert_eq!(institution.to_string(), institution.name_ja());
+  }
+  assert_eq!(
+    Institution::from_str("司法試験管理委員会規則"),
+    Ok(Institution::BarExaminationManagementCommittee)
+  );
+  assert_eq!(
+    Institution::BarExaminationManagementCommitteeCode17.to_string(),
+    "司法試験管理委員会規則"
+  );
 }
 
 /// 法令IDの詳細 <https://elaws.e-gov.go.jp/file/LawIdNamingConvention.pdf> を参照
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LawIdType {
   /// 憲法
   Constitution,
@@ -968,195 +2223,715 @@ impl Display for LawIdType {
         f,
         "RJNJ{kind:02}{kind_serial_number:03}{amendment_serial_number:03}"
       ),
-      Regulation { institution, num } => write!(f, "R{:>08}{num:03}", institution.to_int()),
+      Regulation { institution, num } => write!(f, "R{:08}{num:03}", institution.to_int()),
       PrimeMinisterDecision { month, day, num } => write!(f, "RPMD{month:02}{day:02}{num:04}"),
     }
   }
 }
 
-impl FromStr for LawIdType {
-  type Err = ();
-  fn from_str(s: &str) -> Result<Self, Self::Err> {
-    use LawIdType::*;
-    if s == "CONSTITUTION" {
-      Ok(Constitution)
-    } else if &s[0..=1] == "AC" {
-      let rippou_type_s = &s[2..=8].parse::<usize>().map_err(|_| ())?;
-      let rippou_type = if *rippou_type_s == 0 {
-        RippouType::Kakuhou
-      } else if *rippou_type_s == 1000000 {
-        RippouType::Syuin
-      } else if *rippou_type_s == 100000 {
-        RippouType::Sanin
-      } else {
-        return Err(());
-      };
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(Act { rippou_type, num })
-    } else if &s[0..=1] == "CO" {
-      let efficacy_s = &s[2..=8].parse::<usize>().map_err(|_| ())?;
-      let efficacy = if *efficacy_s == 0 {
-        LawEfficacy::CabinetOrder
-      } else if *efficacy_s == 1000000 {
-        LawEfficacy::Law
-      } else {
-        return Err(());
-      };
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(CabinetOrder { efficacy, num })
-    } else if &s[0..=1] == "IO" {
-      let efficacy_s = &s[2..=8].parse::<usize>().map_err(|_| ())?;
-      let efficacy = if *efficacy_s == 0 {
-        LawEfficacy::CabinetOrder
-      } else if *efficacy_s == 1000000 {
-        LawEfficacy::Law
-      } else {
-        return Err(());
-      };
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(ImperialOrder { efficacy, num })
-    } else if &s[0..=1] == "DF" {
-      let efficacy_s = &s[2..=8].parse::<usize>().map_err(|_| ())?;
-      let efficacy = if *efficacy_s == 0 {
-        LawEfficacy::CabinetOrder
-      } else if *efficacy_s == 1000000 {
-        LawEfficacy::Law
-      } else {
-        return Err(());
-      };
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(DajokanFukoku { efficacy, num })
-    } else if &s[0..=1] == "DT" {
-      let efficacy_s = &s[2..=8].parse::<usize>().map_err(|_| ())?;
-      let efficacy = if *efficacy_s == 0 {
-        LawEfficacy::CabinetOrder
-      } else if *efficacy_s == 1000000 {
-        LawEfficacy::Law
-      } else {
-        return Err(());
-      };
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(DajokanTasshi { efficacy, num })
-    } else if &s[0..=1] == "DH" {
-      let efficacy_s = &s[2..=8].parse::<usize>().map_err(|_| ())?;
-      let efficacy = if *efficacy_s == 0 {
-        LawEfficacy::CabinetOrder
-      } else if *efficacy_s == 1000000 {
-        LawEfficacy::Law
-      } else {
-        return Err(());
-      };
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(DajokanHutatsu { efficacy, num })
-    } else if &s[0..=0] == "M" {
-      let ministry = match &s[1..=1] {
-        "1" => {
-          let n = usize::from_str_radix(&s[2..=8], 16).map_err(|_| ())?;
-          let byte_s = format!("{n:028b}");
-          let l = list_from_str::<M1Ministry>(&byte_s)?;
-          Ministry::M1(l)
-        }
-        "2" => {
-          let n = usize::from_str_radix(&s[2..=8], 16).map_err(|_| ())?;
-          let byte_s = format!("{n:028b}");
-          let l = list_from_str::<M2Ministry>(&byte_s)?;
-          Ministry::M2(l)
-        }
-        "3" => {
-          let n = usize::from_str_radix(&s[2..=8], 16).map_err(|_| ())?;
-          let byte_s = format!("{n:028b}");
-          let l = list_from_str::<M3Ministry>(&byte_s)?;
-          Ministry::M3(l)
-        }
-        "4" => {
-          let n = usize::from_str_radix(&s[2..=8], 16).map_err(|_| ())?;
-          let byte_s = format!("{n:028b}");
-          let l = list_from_str::<M4Ministry>(&byte_s)?;
-          Ministry::M4(l)
-        }
-        "5" => {
-          let n = usize::from_str_radix(&s[2..=8], 16).map_err(|_| ())?;
-          let byte_s = format!("{n:028b}");
-          let l = list_from_str::<M5Ministry>(&byte_s)?;
-          Ministry::M5(l)
-        }
-        "6" => {
-          let n = usize::from_str_radix(&s[2..=8], 16).map_err(|_| ())?;
-          let byte_s = format!("{n:028b}");
-          let l = list_from_str::<M6Ministry>(&byte_s)?;
-          Ministry::M6(l)
-        }
-        _ => return Err(()),
-      };
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(MinistryOrder { ministry, num })
-    } else if &s[0..=3] == "RJNJ" {
-      let kind = s[4..=5].parse::<usize>().map_err(|_| ())?;
-      let kind_serial_number = s[6..=8].parse::<usize>().map_err(|_| ())?;
-      let amendment_serial_number = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(Jinjin {
-        kind,
-        kind_serial_number,
-        amendment_serial_number,
-      })
-    } else if &s[0..=3] == "RPMD" {
-      let month = s[4..=5].parse::<usize>().map_err(|_| ())?;
-      let day = s[6..=7].parse::<usize>().map_err(|_| ())?;
-      let num = s[8..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(PrimeMinisterDecision { month, day, num })
-    } else if &s[0..=0] == "R" {
-      let institution_s = &s[1..=8].parse::<usize>().map_err(|_| ())?;
-      let institution = Institution::from_int(*institution_s).ok_or(())?;
-      let num = s[9..=11].parse::<usize>().map_err(|_| ())?;
-      Ok(Regulation { institution, num })
-    } else {
-      Err(())
+/// 法令IDの解析時のエラー
+/// `offset`は解析した文字列中のバイト位置を表す
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LawIdParseError {
+  /// 文字列の長さが違う
+  #[error("invalid length: expected {expected} bytes, found {found}")]
+  InvalidLength { expected: usize, found: usize },
+  /// ASCII以外の文字が含まれている
+  #[error("non-ASCII character at byte {offset}")]
+  NonAscii { offset: usize },
+  /// 元号を表す数字が不正
+  #[error("unknown era digit `{found}` at byte {offset}")]
+  UnknownEra { offset: usize, found: char },
+  /// 法令の種類を表す接頭辞が不正
+  #[error("unknown law type prefix `{found}` at byte {offset}")]
+  UnknownPrefix { offset: usize, found: String },
+  /// 数字として読めない
+  #[error("invalid number `{found}` at byte {offset}")]
+  InvalidNumber { offset: usize, found: String },
+  /// 法律の立法の種類を表す部分が不正
+  #[error("unknown rippou type `{found}` at byte {offset}")]
+  UnknownRippouType { offset: usize, found: String },
+  /// 効力の種類を表す部分が不正
+  #[error("unknown efficacy `{found}` at byte {offset}")]
+  UnknownEfficacy { offset: usize, found: String },
+  /// 府省を表すビットが不正
+  #[error("unknown ministry bit {bit} at byte {offset}")]
+  UnknownMinistryBit { offset: usize, bit: usize },
+  /// 機関を表すコードが不正
+  #[error("unknown institution code {code} at byte {offset}")]
+  UnknownInstitution { offset: usize, code: usize },
+  /// 区切られた項目が足りない
+  #[error("missing field `{field}` at byte {offset}")]
+  MissingField { offset: usize, field: String },
+  /// 日付が不正
+  #[error("invalid date at byte {offset}: {error}")]
+  InvalidDate { offset: usize, error: DateError },
+}
+
+impl LawIdParseError {
+  /// エラーの位置を`n`バイトずらす
+  fn shift(self, n: usize) -> Self {
+    use LawIdParseError::*;
+    match self {
+      InvalidLength { expected, found } => InvalidLength { expected, found },
+      NonAscii { offset } => NonAscii { offset: offset + n },
+      UnknownEra { offset, found } => UnknownEra {
+        offset: offset + n,
+        found,
+      },
+      UnknownPrefix { offset, found } => UnknownPrefix {
+        offset: offset + n,
+        found,
+      },
+      InvalidNumber { offset, found } => InvalidNumber {
+        offset: offset + n,
+        found,
+      },
+      UnknownRippouType { offset, found } => UnknownRippouType {
+        offset: offset + n,
+        found,
+      },
+      UnknownEfficacy { offset, found } => UnknownEfficacy {
+        offset: offset + n,
+        found,
+      },
+      UnknownMinistryBit { offset, bit } => UnknownMinistryBit {
+        offset: offset + n,
+        bit,
+      },
+      UnknownInstitution { offset, code } => UnknownInstitution {
+        offset: offset + n,
+        code,
+      },
+      MissingField { offset, field } => MissingField {
+        offset: offset + n,
+        field,
+      },
+      InvalidDate { offset, error } => InvalidDate {
+        offset: offset + n,
+        error,
+      },
     }
   }
 }
 
-/// 法令ID： <https://elaws.e-gov.go.jp/file/LawIdNamingConvention.pdf>を参照
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
-pub struct LawId {
-  era: Era,
-  year: usize,
-  law_id_type: LawIdType,
+/// ASCII文字のみで、長さが`len`であることを確かめる
+fn check_id_str(s: &str, len: usize) -> Result<(), LawIdParseError> {
+  if let Some(offset) = s.bytes().position(|b| !b.is_ascii()) {
+    return Err(LawIdParseError::NonAscii { offset });
+  }
+  if s.len() != len {
+    return Err(LawIdParseError::InvalidLength {
+      expected: len,
+      found: s.len(),
+    });
+  }
+  Ok(())
 }
 
-impl Display for LawId {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    match self.era {
-      Era::Meiji => write!(f, "1{:02}{}", self.year, self.law_id_type),
-      Era::Taisho => write!(f, "2{:02}{}", self.year, self.law_id_type),
-      Era::Showa => write!(f, "3{:02}{}", self.year, self.law_id_type),
-      Era::Heisei => write!(f, "4{:02}{}", self.year, self.law_id_type),
-      Era::Reiwa => write!(f, "5{:02}{}", self.year, self.law_id_type),
-    }
+/// `s[start..end]`を`radix`進数として読む
+/// `s`はASCII文字のみで、範囲内の長さを持っていなければならない
+fn parse_id_number(
+  s: &str,
+  start: usize,
+  end: usize,
+  radix: u32,
+) -> Result<usize, LawIdParseError> {
+  let t = &s[start..end];
+  if !t.chars().all(|c| c.is_digit(radix)) {
+    return Err(LawIdParseError::InvalidNumber {
+      offset: start,
+      found: t.to_string(),
+    });
   }
+  usize::from_str_radix(t, radix).map_err(|_| LawIdParseError::InvalidNumber {
+    offset: start,
+    found: t.to_string(),
+  })
 }
 
-impl FromStr for LawId {
-  type Err = ();
+/// 政令などの効力の種類を読む
+fn parse_efficacy(s: &str) -> Result<LawEfficacy, LawIdParseError> {
+  match &s[2..=8] {
+    "0000000" => Ok(LawEfficacy::CabinetOrder),
+    "1000000" => Ok(LawEfficacy::Law),
+    found => Err(LawIdParseError::UnknownEfficacy {
+      offset: 2,
+      found: found.to_string(),
+    }),
+  }
+}
+
+impl FromStr for LawIdType {
+  type Err = LawIdParseError;
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let era = match &s[0..=0] {
-      "1" => Era::Meiji,
-      "2" => Era::Taisho,
-      "3" => Era::Showa,
-      "4" => Era::Heisei,
-      "5" => Era::Reiwa,
-      _ => return Err(()),
-    };
-    let year = &s[1..=2].parse::<usize>().map_err(|_| ())?;
-    let law_id_type = &s[3..=14].parse::<LawIdType>().map_err(|_| ())?;
-    Ok(LawId {
-      era,
-      year: *year,
-      law_id_type: law_id_type.clone(),
-    })
+    use LawIdType::*;
+    check_id_str(s, 12)?;
+    if s == "CONSTITUTION" {
+      return Ok(Constitution);
+    }
+    let num = || parse_id_number(s, 9, 12, 10);
+    match &s[0..=1] {
+      "AC" => {
+        let rippou_type = match &s[2..=8] {
+          "0000000" => RippouType::Kakuhou,
+          "1000000" => RippouType::Syuin,
+          "0100000" => RippouType::Sanin,
+          found => {
+            return Err(LawIdParseError::UnknownRippouType {
+              offset: 2,
+              found: found.to_string(),
+            })
+          }
+        };
+        Ok(Act {
+          rippou_type,
+          num: num()?,
+        })
+      }
+      "CO" => Ok(CabinetOrder {
+        efficacy: parse_efficacy(s)?,
+        num: num()?,
+      }),
+      "IO" => Ok(ImperialOrder {
+        efficacy: parse_efficacy(s)?,
+        num: num()?,
+      }),
+      "DF" => Ok(DajokanFukoku {
+        efficacy: parse_efficacy(s)?,
+        num: num()?,
+      }),
+      "DT" => Ok(DajokanTasshi {
+        efficacy: parse_efficacy(s)?,
+        num: num()?,
+      }),
+      "DH" => Ok(DajokanHutatsu {
+        efficacy: parse_efficacy(s)?,
+        num: num()?,
+      }),
+      "M1" | "M2" | "M3" | "M4" | "M5" | "M6" => {
+        let n = parse_id_number(s, 2, 9, 16)?;
+        let byte_s = format!("{n:028b}");
+        let ministry = match &s[1..=1] {
+          "1" => list_from_str(&byte_s).map(Ministry::M1),
+          "2" => list_from_str(&byte_s).map(Ministry::M2),
+          "3" => list_from_str(&byte_s).map(Ministry::M3),
+          "4" => list_from_str(&byte_s).map(Ministry::M4),
+          "5" => list_from_str(&byte_s).map(Ministry::M5),
+          _ => list_from_str(&byte_s).map(Ministry::M6),
+        }
+        .map_err(|e| e.shift(2))?;
+        Ok(MinistryOrder {
+          ministry,
+          num: num()?,
+        })
+      }
+      _ if s.starts_with("RJNJ") => Ok(Jinjin {
+        kind: parse_id_number(s, 4, 6, 10)?,
+        kind_serial_number: parse_id_number(s, 6, 9, 10)?,
+        amendment_serial_number: num()?,
+      }),
+      _ if s.starts_with("RPMD") => Ok(PrimeMinisterDecision {
+        month: parse_id_number(s, 4, 6, 10)?,
+        day: parse_id_number(s, 6, 8, 10)?,
+        num: parse_id_number(s, 8, 12, 10)?,
+      }),
+      _ if s.starts_with('R') => {
+        let code = parse_id_number(s, 1, 9, 10)?;
+        let institution = Institution::from_int(code)
+          .ok_or(LawIdParseError::UnknownInstitution { offset: 1, code })?;
+        Ok(Regulation {
+          institution,
+          num: num()?,
+        })
+      }
+      found => Err(LawIdParseError::UnknownPrefix {
+        offset: 0,
+        found: found.to_string(),
+      }),
+    }
   }
 }
 
-#[test]
+/// 法形式
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LawKind {
+  /// 憲法
+  Constitution,
+  /// 法律
+  Act,
+  /// 政令
+  CabinetOrder,
+  /// 勅令
+  ImperialOrder,
+  /// 太政官布告
+  DajokanFukoku,
+  /// 太政官達
+  DajokanTasshi,
+  /// 太政官布達
+  DajokanHutatsu,
+  /// 府省令
+  MinistryOrder,
+  /// 人事院規則
+  Jinjin,
+  /// 機関の規則
+  Regulation,
+  /// 内閣総理大臣決定
+  PrimeMinisterDecision,
+}
+
+/// 法令の形式的効力の順位
+/// 上位のものほど大きくなる
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LawRank {
+  /// 規則
+  Regulation,
+  /// 府省令
+  MinistryOrder,
+  /// 政令
+  CabinetOrder,
+  /// 法律
+  Act,
+  /// 憲法
+  Constitution,
+}
+
+impl LawKind {
+  /// 形式的効力の順位
+  /// 勅令と太政官による法令は政令と同じ順位とする
+  /// 法律の効力を持つものを区別する場合は[`LawId::rank`]を使う
+  pub fn rank(&self) -> LawRank {
+    use LawKind::*;
+    match self {
+      Constitution => LawRank::Constitution,
+      Act => LawRank::Act,
+      CabinetOrder | ImperialOrder | DajokanFukoku | DajokanTasshi | DajokanHutatsu => {
+        LawRank::CabinetOrder
+      }
+      MinistryOrder => LawRank::MinistryOrder,
+      Jinjin | Regulation | PrimeMinisterDecision => LawRank::Regulation,
+    }
+  }
+
+  /// 現行憲法下でも制定される法形式かどうか
+  /// 勅令と太政官による法令は`false`になる
+  pub fn is_current_form(&self) -> bool {
+    use LawKind::*;
+    !matches!(
+      self,
+      ImperialOrder | DajokanFukoku | DajokanTasshi | DajokanHutatsu
+    )
+  }
+}
+
+impl Display for LawKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    use LawKind::*;
+    let s = match self {
+      Constitution => "憲法",
+      Act => "法律",
+      CabinetOrder => "政令",
+      ImperialOrder => "勅令",
+      DajokanFukoku => "太政官布告",
+      DajokanTasshi => "太政官達",
+      DajokanHutatsu => "太政官布達",
+      MinistryOrder => "府省令",
+      Jinjin => "人事院規則",
+      Regulation => "規則",
+      PrimeMinisterDecision => "内閣総理大臣決定",
+    };
+    write!(f, "{s}")
+  }
+}
+
+impl Display for LawRank {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let s = match self {
+      LawRank::Constitution => "憲法",
+      LawRank::Act => "法律",
+      LawRank::CabinetOrder => "政令",
+      LawRank::MinistryOrder => "府省令",
+      LawRank::Regulation => "規則",
+    };
+    write!(f, "{s}")
+  }
+}
+
+impl LawIdType {
+  /// 法形式
+  pub fn kind(&self) -> LawKind {
+    match self {
+      LawIdType::Constitution => LawKind::Constitution,
+      LawIdType::Act { .. } => LawKind::Act,
+      LawIdType::CabinetOrder { .. } => LawKind::CabinetOrder,
+      LawIdType::ImperialOrder { .. } => LawKind::ImperialOrder,
+      LawIdType::DajokanFukoku { .. } => LawKind::DajokanFukoku,
+      LawIdType::DajokanTasshi { .. } => LawKind::DajokanTasshi,
+      LawIdType::DajokanHutatsu { .. } => LawKind::DajokanHutatsu,
+      LawIdType::MinistryOrder { .. } => LawKind::MinistryOrder,
+      LawIdType::Jinjin { .. } => LawKind::Jinjin,
+      LawIdType::Regulation { .. } => LawKind::Regulation,
+      LawIdType::PrimeMinisterDecision { .. } => LawKind::PrimeMinisterDecision,
+    }
+  }
+
+  /// 形式的効力の順位
+  /// 法律の効力を持つ政令・勅令・太政官による法令は法律と同じ順位になる
+  pub fn rank(&self) -> LawRank {
+    match self {
+      LawIdType::CabinetOrder { efficacy, .. }
+      | LawIdType::ImperialOrder { efficacy, .. }
+      | LawIdType::DajokanFukoku { efficacy, .. }
+      | LawIdType::DajokanTasshi { efficacy, .. }
+      | LawIdType::DajokanHutatsu { efficacy, .. } => match efficacy {
+        LawEfficacy::Law => LawRank::Act,
+        LawEfficacy::CabinetOrder => LawRank::CabinetOrder,
+      },
+      _ => self.kind().rank(),
+    }
+  }
+}
+
+/// 法令ID： <https://elaws.e-gov.go.jp/file/LawIdNamingConvention.pdf>を参照
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LawId {
+  era: Era,
+  year: usize,
+  law_id_type: LawIdType,
+}
+
+impl Display for LawId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.era {
+      Era::Meiji => write!(f, "1{:02}{}", self.year, self.law_id_type),
+      Era::Taisho => write!(f, "2{:02}{}", self.year, self.law_id_type),
+      Era::Showa => write!(f, "3{:02}{}", self.year, self.law_id_type),
+      Era::Heisei => write!(f, "4{:02}{}", self.year, self.law_id_type),
+      Era::Reiwa => write!(f, "5{:02}{}", self.year, self.law_id_type),
+    }
+  }
+}
+
+impl FromStr for LawId {
+  type Err = LawIdParseError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    check_id_str(s, 15)?;
+    let era = match &s[0..=0] {
+      "1" => Era::Meiji,
+      "2" => Era::Taisho,
+      "3" => Era::Showa,
+      "4" => Era::Heisei,
+      "5" => Era::Reiwa,
+      _ => {
+        return Err(LawIdParseError::UnknownEra {
+          offset: 0,
+          found: s.chars().next().unwrap_or_default(),
+        })
+      }
+    };
+    let year = parse_id_number(s, 1, 3, 10)?;
+    let law_id_type = s[3..].parse::<LawIdType>().map_err(|e| e.shift(3))?;
+    Ok(LawId {
+      era,
+      year,
+      law_id_type,
+    })
+  }
+}
+
+impl LawId {
+  /// 元号・年・法令の種類から法令IDを作る
+  ///
+  /// 法令IDの文字列として表せない値（桁あふれなど）の場合は、`FromStr`と同じエラーを返す
+  pub fn new(era: Era, year: usize, law_id_type: LawIdType) -> Result<Self, LawIdParseError> {
+    Self {
+      era,
+      year,
+      law_id_type,
+    }
+    .to_string()
+    .parse()
+  }
+
+  /// 元号
+  pub fn era(&self) -> &Era {
+    &self.era
+  }
+
+  /// 年
+  pub fn year(&self) -> usize {
+    self.year
+  }
+
+  /// 法令の種類と番号
+  pub fn law_id_type(&self) -> &LawIdType {
+    &self.law_id_type
+  }
+
+  /// 法令IDから分かる範囲の日付
+  /// 内閣総理大臣決定の場合は月日も含まれる
+  pub fn date_hint(&self) -> Option<Date> {
+    let (month, day) = match &self.law_id_type {
+      LawIdType::PrimeMinisterDecision { month, day, .. } => (Some(*month), Some(*day)),
+      _ => (None, None),
+    };
+    Date::try_new(self.era.clone(), self.year, month, day).ok()
+  }
+
+  /// 法形式
+  pub fn kind(&self) -> LawKind {
+    self.law_id_type.kind()
+  }
+
+  /// 形式的効力の順位
+  pub fn rank(&self) -> LawRank {
+    self.law_id_type.rank()
+  }
+
+  /// 現行憲法下でも制定される法形式かどうか
+  pub fn is_current_form(&self) -> bool {
+    self.kind().is_current_form()
+  }
+}
+
+#[test]
+fn check_law_kind() {
+  let lst = [
+    (
+      "321CONSTITUTION",
+      LawKind::Constitution,
+      LawRank::Constitution,
+      "憲法",
+    ),
+    ("325AC0000000131", LawKind::Act, LawRank::Act, "法律"),
+    (
+      "325CO0000000350",
+      LawKind::CabinetOrder,
+      LawRank::CabinetOrder,
+      "政令",
+    ),
+    (
+      "322IO1000000074",
+      LawKind::ImperialOrder,
+      LawRank::Act,
+      "勅令",
+    ),
+    (
+      "322IO0000000001",
+      LawKind::ImperialOrder,
+      LawRank::CabinetOrder,
+      "勅令",
+    ),
+    (
+      "505M60000400060",
+      LawKind::MinistryOrder,
+      LawRank::MinistryOrder,
+      "府省令",
+    ),
+    (
+      "322R00000001001",
+      LawKind::Regulation,
+      LawRank::Regulation,
+      "規則",
+    ),
+    (
+      "327RJNJ09017000",
+      LawKind::Jinjin,
+      LawRank::Regulation,
+      "人事院規則",
+    ),
+  ];
+  for (s, kind, rank, name) in lst {
+    let law_id = LawId::from_str(s).unwrap();
+    assert_eq!(law_id.kind(), kind, "{s}");
+    assert_eq!(law_id.rank(), rank, "{s}");
+    assert_eq!(kind.to_string(), name);
+    assert_eq!(law_id.is_current_form(), kind != LawKind::ImperialOrder);
+  }
+  assert!(LawRank::Constitution > LawRank::Act);
+  assert!(LawRank::Act > LawRank::CabinetOrder);
+  assert!(LawRank::CabinetOrder > LawRank::MinistryOrder);
+  assert!(LawRank::MinistryOrder > LawRank::Regulation);
+  assert_eq!(LawKind::DajokanFukoku.rank(), LawRank::CabinetOrder);
+  assert!(!LawKind::DajokanTasshi.is_current_form());
+}
+
+impl PartialOrd for LawId {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// 元号・年・法令の種類と番号の順で比較する
+impl Ord for LawId {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    (era_index(&self.era), self.year, &self.law_id_type).cmp(&(
+      era_index(&other.era),
+      other.year,
+      &other.law_id_type,
+    ))
+  }
+}
+
+/// 法令IDを`"325AC0000000131"`のような文字列としてシリアライズする
+///
+/// ```
+/// use jplaw_data_types::law::LawId;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Index {
+///   #[serde(with = "jplaw_data_types::law::law_id_as_string")]
+///   id: LawId,
+///   #[serde(with = "jplaw_data_types::law::law_id_as_string::option")]
+///   patch_id: Option<LawId>,
+/// }
+/// ```
+pub mod law_id_as_string {
+  use super::LawId;
+  use serde::{Deserialize, Deserializer, Serializer};
+  use std::str::FromStr;
+
+  pub fn serialize<S: Serializer>(id: &LawId, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(id)
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<LawId, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    LawId::from_str(&s).map_err(serde::de::Error::custom)
+  }
+
+  /// `Option<LawId>`に対して使う
+  pub mod option {
+    use super::LawId;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(id: &Option<LawId>, serializer: S) -> Result<S::Ok, S::Error> {
+      match id {
+        Some(id) => serializer.collect_str(id),
+        None => serializer.serialize_none(),
+      }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+      deserializer: D,
+    ) -> Result<Option<LawId>, D::Error> {
+      let s = Option::<String>::deserialize(deserializer)?;
+      s.map(|s| LawId::from_str(&s).map_err(serde::de::Error::custom))
+        .transpose()
+    }
+  }
+}
+
+#[test]
+fn check_law_id_accessor() {
+  let id = LawId::new(
+    Era::Heisei,
+    13,
+    LawIdType::PrimeMinisterDecision {
+      month: 1,
+      day: 6,
+      num: 1,
+    },
+  )
+  .unwrap();
+  assert_eq!(id.to_string(), "413RPMD01060001");
+  assert_eq!(id.era(), &Era::Heisei);
+  assert_eq!(id.year(), 13);
+  assert_eq!(
+    id.date_hint(),
+    Some(Date::new(Era::Heisei, 13, Some(1), Some(6)))
+  );
+  let id = LawId::from_str("325AC0000000131").unwrap();
+  assert_eq!(
+    id.law_id_type(),
+    &LawIdType::Act {
+      rippou_type: RippouType::Kakuhou,
+      num: 131
+    }
+  );
+  assert_eq!(id.date_hint(), Some(Date::new(Era::Showa, 25, None, None)));
+  assert_eq!(
+    LawId::new(
+      Era::Showa,
+      100,
+      LawIdType::Act {
+        rippou_type: RippouType::Kakuhou,
+        num: 131
+      }
+    ),
+    Err(LawIdParseError::InvalidLength {
+      expected: 15,
+      found: 16
+    })
+  );
+  assert!(LawId::new(
+    Era::Heisei,
+    13,
+    LawIdType::PrimeMinisterDecision {
+      month: 1,
+      day: 6,
+      num: 10000,
+    },
+  )
+  .is_err());
+}
+
+#[test]
+fn check_law_id_ord() {
+  let mut lst = [
+    "505M60000400060",
+    "129AC0000000089",
+    "345AC0000000089",
+    "325M50001000004",
+    "325AC0000000131",
+    "325AC0000000089",
+  ]
+  .iter()
+  .map(|s| LawId::from_str(s).unwrap())
+  .collect::<Vec<_>>();
+  lst.sort();
+  let lst = lst.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+  assert_eq!(
+    lst,
+    [
+      "129AC0000000089",
+      "325AC0000000089",
+      "325AC0000000131",
+      "325M50001000004",
+      "345AC0000000089",
+      "505M60000400060",
+    ]
+  );
+}
+
+#[test]
+fn check_law_id_as_string() {
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Index {
+    #[serde(with = "law_id_as_string")]
+    id: LawId,
+    #[serde(with = "law_id_as_string::option")]
+    patch_id: Option<LawId>,
+  }
+  let index = Index {
+    id: LawId::from_str("325AC0000000131").unwrap(),
+    patch_id: None,
+  };
+  let s = serde_json::to_string(&index).unwrap();
+  assert_eq!(s, r#"{"id":"325AC0000000131","patch_id":null}"#);
+  assert_eq!(serde_json::from_str::<Index>(&s).unwrap(), index);
+  let index = Index {
+    id: LawId::from_str("325AC0000000131").unwrap(),
+    patch_id: Some(LawId::from_str("505AC0000000053").unwrap()),
+  };
+  let s = serde_json::to_string(&index).unwrap();
+  assert_eq!(serde_json::from_str::<Index>(&s).unwrap(), index);
+  assert!(serde_json::from_str::<Index>(r#"{"id":"325AC00","patch_id":null}"#).is_err());
+}
+
+#[test]
 fn check_from_str_law_id() {
   let s = "325M50001000004";
   let law_id = LawId::from_str(s).unwrap();
@@ -1238,7 +3013,7 @@ fn check_from_str_law_id_4() {
 
 #[test]
 fn check_from_str_law_id_lst() {
-  let v = vec![
+  let v = [
     "325M50001000004",
     "345AC0000000089",
     "505M60000400060",
@@ -1252,6 +3027,493 @@ fn check_from_str_law_id_lst() {
   }
 }
 
+/// 法令番号の種類
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LawNumType {
+  /// 憲法
+  Constitution,
+  /// 法律
+  Act { num: usize },
+  /// 政令
+  CabinetOrder { num: usize },
+  /// 勅令
+  ImperialOrder { num: usize },
+  /// 太政官布告
+  DajokanFukoku { num: usize },
+  /// 太政官達
+  DajokanTasshi { num: usize },
+  /// 太政官布達
+  DajokanHutatsu { num: usize },
+  /// 府省令・規則
+  /// 「経済産業省・環境省令」のような共同命令は`["経済産業省令", "環境省令"]`のように分けて保持する
+  Ordinance { issuers: Vec<String>, num: usize },
+  /// 人事院規則（「人事院規則一四―七」「人事院規則九―七―六〇」など）
+  Jinjin {
+    /// 規則の分類
+    kind: usize,
+    /// 規則の分類中の連番
+    kind_serial_number: usize,
+    /// 改正規則の連番（改正規則でない場合は0）
+    amendment_serial_number: usize,
+  },
+  /// 内閣総理大臣決定（「平成十三年一月六日内閣総理大臣決定」など）
+  PrimeMinisterDecision { month: usize, day: usize },
+}
+
+/// 法令番号（「昭和二十五年法律第百三十一号」など）
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LawNum {
+  pub era: Era,
+  pub year: usize,
+  pub law_num_type: LawNumType,
+}
+
+/// 法令番号の解析時のエラー
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LawNumParseError {
+  #[error("unknown era: {0}")]
+  UnknownEra(String),
+  #[error("invalid year: {0}")]
+  InvalidYear(String),
+  #[error("unknown law type: {0}")]
+  UnknownType(String),
+  #[error("invalid number: {0}")]
+  InvalidNumber(String),
+}
+
+/// 人事院規則の番号の区切り文字
+const JINJIN_SEPARATORS: [char; 6] = ['―', '－', '-', '‐', '—', 'ー'];
+
+/// 「経済産業省・環境省令」を`["経済産業省令", "環境省令"]`に分ける
+fn split_issuers(s: &str) -> Vec<String> {
+  let suffix = if s.ends_with("規則") {
+    "規則"
+  } else {
+    "令"
+  };
+  s.split('・')
+    .map(|name| {
+      if name.ends_with(suffix) {
+        name.to_string()
+      } else {
+        format!("{name}{suffix}")
+      }
+    })
+    .collect()
+}
+
+/// `["経済産業省令", "環境省令"]`を「経済産業省・環境省令」にまとめる
+fn join_issuers<S: AsRef<str>>(issuers: &[S]) -> String {
+  let suffix = match issuers.last() {
+    Some(name) if name.as_ref().ends_with("規則") => "規則",
+    _ => "令",
+  };
+  let len = issuers.len();
+  issuers
+    .iter()
+    .enumerate()
+    .map(|(i, name)| {
+      let name = name.as_ref();
+      if i + 1 < len {
+        name.strip_suffix(suffix).unwrap_or(name)
+      } else {
+        name
+      }
+    })
+    .collect::<Vec<_>>()
+    .join("・")
+}
+
+impl Display for LawNum {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    use LawNumType::*;
+    write!(f, "{}", era_name(&self.era))?;
+    if self.year == 1 {
+      write!(f, "元年")?;
+    } else {
+      write!(f, "{}年", to_kansuji(self.year))?;
+    }
+    let (name, num) = match &self.law_num_type {
+      Constitution => return write!(f, "憲法"),
+      Act { num } => ("法律".to_string(), num),
+      CabinetOrder { num } => ("政令".to_string(), num),
+      ImperialOrder { num } => ("勅令".to_string(), num),
+      DajokanFukoku { num } => ("太政官布告".to_string(), num),
+      DajokanTasshi { num } => ("太政官達".to_string(), num),
+      DajokanHutatsu { num } => ("太政官布達".to_string(), num),
+      Ordinance { issuers, num } => (join_issuers(issuers), num),
+      Jinjin {
+        kind,
+        kind_serial_number,
+        amendment_serial_number,
+      } => {
+        write!(
+          f,
+          "人事院規則{}―{}",
+          to_kansuji_positional(*kind),
+          to_kansuji_positional(*kind_serial_number)
+        )?;
+        if *amendment_serial_number != 0 {
+          write!(f, "―{}", to_kansuji_positional(*amendment_serial_number))?;
+        }
+        return Ok(());
+      }
+      PrimeMinisterDecision { month, day } => {
+        return write!(
+          f,
+          "{}月{}日内閣総理大臣決定",
+          to_kansuji(*month),
+          to_kansuji(*day)
+        )
+      }
+    };
+    write!(f, "{name}第{}号", to_kansuji(*num))
+  }
+}
+
+impl FromStr for LawNum {
+  type Err = LawNumParseError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    use LawNumType::*;
+    let text = s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    let (era, rest) =
+      split_era_prefix(&text).ok_or_else(|| LawNumParseError::UnknownEra(s.to_string()))?;
+    let (year_s, rest) = rest
+      .split_once('年')
+      .ok_or_else(|| LawNumParseError::InvalidYear(s.to_string()))?;
+    let year = if year_s == "元" {
+      1
+    } else {
+      parse_number_ja(year_s).ok_or_else(|| LawNumParseError::InvalidYear(year_s.to_string()))?
+    };
+    let parse_num = |num_s: &str| {
+      parse_number_ja(num_s).ok_or_else(|| LawNumParseError::InvalidNumber(num_s.to_string()))
+    };
+    let law_num_type = if rest == "憲法" {
+      Constitution
+    } else if let Some(num_s) = rest.strip_prefix("人事院規則") {
+      let nums = num_s
+        .split(|c| JINJIN_SEPARATORS.contains(&c))
+        .map(parse_num)
+        .collect::<Result<Vec<_>, _>>()?;
+      match nums.as_slice() {
+        [kind, kind_serial_number] => Jinjin {
+          kind: *kind,
+          kind_serial_number: *kind_serial_number,
+          amendment_serial_number: 0,
+        },
+        [kind, kind_serial_number, amendment_serial_number] => Jinjin {
+          kind: *kind,
+          kind_serial_number: *kind_serial_number,
+          amendment_serial_number: *amendment_serial_number,
+        },
+        _ => return Err(LawNumParseError::InvalidNumber(num_s.to_string())),
+      }
+    } else if let Some(date_s) = rest.strip_suffix("内閣総理大臣決定") {
+      let (month_s, day_s) = date_s
+        .strip_suffix('日')
+        .and_then(|s| s.split_once('月'))
+        .ok_or_else(|| LawNumParseError::InvalidNumber(date_s.to_string()))?;
+      PrimeMinisterDecision {
+        month: parse_num(month_s)?,
+        day: parse_num(day_s)?,
+      }
+    } else {
+      let (name, num_s) = rest
+        .strip_suffix('号')
+        .and_then(|s| s.rsplit_once('第'))
+        .ok_or_else(|| LawNumParseError::UnknownType(rest.to_string()))?;
+      let num = parse_num(num_s)?;
+      match name {
+        "法律" => Act { num },
+        "政令" => CabinetOrder { num },
+        "勅令" => ImperialOrder { num },
+        "太政官布告" => DajokanFukoku { num },
+        "太政官達" => DajokanTasshi { num },
+        "太政官布達" => DajokanHutatsu { num },
+        _ if !name.starts_with('・') && (name.ends_with('令') || name.ends_with("規則")) => {
+          Ordinance {
+            issuers: split_issuers(name),
+            num,
+          }
+        }
+        _ => return Err(LawNumParseError::UnknownType(name.to_string())),
+      }
+    };
+    Ok(LawNum {
+      era,
+      year,
+      law_num_type,
+    })
+  }
+}
+
+impl From<&LawId> for LawNum {
+  fn from(id: &LawId) -> Self {
+    LawNum::from_law_id(id)
+  }
+}
+
+impl LawNum {
+  /// 法令IDから法令番号を得る
+  pub fn from_law_id(id: &LawId) -> Self {
+    let law_num_type = match &id.law_id_type {
+      LawIdType::Constitution => LawNumType::Constitution,
+      LawIdType::Act { num, .. } => LawNumType::Act { num: *num },
+      LawIdType::CabinetOrder { num, .. } => LawNumType::CabinetOrder { num: *num },
+      LawIdType::ImperialOrder { num, .. } => LawNumType::ImperialOrder { num: *num },
+      LawIdType::DajokanFukoku { num, .. } => LawNumType::DajokanFukoku { num: *num },
+      LawIdType::DajokanTasshi { num, .. } => LawNumType::DajokanTasshi { num: *num },
+      LawIdType::DajokanHutatsu { num, .. } => LawNumType::DajokanHutatsu { num: *num },
+      LawIdType::MinistryOrder { ministry, num } => LawNumType::Ordinance {
+        issuers: ministry
+          .names_ja()
+          .into_iter()
+          .map(|s| s.to_string())
+          .collect(),
+        num: *num,
+      },
+      LawIdType::Regulation { institution, num } => LawNumType::Ordinance {
+        issuers: vec![institution.name_ja()],
+        num: *num,
+      },
+      LawIdType::Jinjin {
+        kind,
+        kind_serial_number,
+        amendment_serial_number,
+      } => LawNumType::Jinjin {
+        kind: *kind,
+        kind_serial_number: *kind_serial_number,
+        amendment_serial_number: *amendment_serial_number,
+      },
+      LawIdType::PrimeMinisterDecision { month, day, .. } => LawNumType::PrimeMinisterDecision {
+        month: *month,
+        day: *day,
+      },
+    };
+    LawNum {
+      era: id.era.clone(),
+      year: id.year,
+      law_num_type,
+    }
+  }
+
+  /// 法令番号から法令IDを得る
+  /// 法律の立法の種類や政令の効力などの法令番号に含まれない情報が必要な場合は`None`になる
+  pub fn to_law_id(&self) -> Option<LawId> {
+    let law_id_type = match &self.law_num_type {
+      LawNumType::Constitution => LawIdType::Constitution,
+      LawNumType::Jinjin {
+        kind,
+        kind_serial_number,
+        amendment_serial_number,
+      } => LawIdType::Jinjin {
+        kind: *kind,
+        kind_serial_number: *kind_serial_number,
+        amendment_serial_number: *amendment_serial_number,
+      },
+      LawNumType::Ordinance { issuers, num } => {
+        let date = Date::try_new(self.era.clone(), self.year, None, None).ok()?;
+        match Ministry::from_names(issuers, &date) {
+          Ok(ministry) => LawIdType::MinistryOrder {
+            ministry: ministry.canonicalize(),
+            num: *num,
+          },
+          Err(_) => match issuers.as_slice() {
+            [issuer] => LawIdType::Regulation {
+              institution: Institution::from_str(issuer).ok()?,
+              num: *num,
+            },
+            _ => return None,
+          },
+        }
+      }
+      _ => return None,
+    };
+    Some(LawId {
+      era: self.era.clone(),
+      year: self.year,
+      law_id_type,
+    })
+  }
+
+  /// 法令IDと矛盾しないかを判定する
+  /// 法令IDにのみ含まれる情報（立法の種類や効力など）は比較しない
+  pub fn is_consistent_with(&self, id: &LawId) -> bool {
+    if self.era != id.era || self.year != id.year {
+      return false;
+    }
+    match (&self.law_num_type, &id.law_id_type) {
+      (
+        LawNumType::Ordinance { issuers, num },
+        LawIdType::MinistryOrder {
+          ministry,
+          num: id_num,
+        },
+      ) => {
+        num == id_num
+          && Ministry::from_names_in_period(issuers, ministry.period())
+            .map_or(false, |m| m.to_bits() == ministry.to_bits())
+      }
+      (
+        LawNumType::Ordinance { issuers, num },
+        LawIdType::Regulation {
+          institution,
+          num: id_num,
+        },
+      ) => {
+        // コード8とコード17は法令番号では区別できないため、機関の名前で比べる
+        let same_name = |issuer: &String| {
+          Institution::from_str(issuer).map_or(false, |i| {
+            i.institution_name() == institution.institution_name()
+          })
+        };
+        num == id_num && matches!(issuers.as_slice(), [issuer] if same_name(issuer))
+      }
+      _ => &LawNum::from_law_id(id) == self,
+    }
+  }
+}
+
+#[test]
+fn check_law_num_from_str() {
+  let lst = [
+    (
+      "昭和二十五年法律第百三十一号",
+      Era::Showa,
+      25,
+      LawNumType::Act { num: 131 },
+    ),
+    (
+      "令和元年政令第一号",
+      Era::Reiwa,
+      1,
+      LawNumType::CabinetOrder { num: 1 },
+    ),
+    ("昭和二十一年憲法", Era::Showa, 21, LawNumType::Constitution),
+    (
+      "明治六年太政官布告第三百三十七号",
+      Era::Meiji,
+      6,
+      LawNumType::DajokanFukoku { num: 337 },
+    ),
+    (
+      "明治二十三年勅令第百五十三号",
+      Era::Meiji,
+      23,
+      LawNumType::ImperialOrder { num: 153 },
+    ),
+    (
+      "令和五年経済産業省・環境省令第一号",
+      Era::Reiwa,
+      5,
+      LawNumType::Ordinance {
+        issuers: vec!["経済産業省令".to_string(), "環境省令".to_string()],
+        num: 1,
+      },
+    ),
+    (
+      "昭和二十四年最高裁判所規則第三号",
+      Era::Showa,
+      24,
+      LawNumType::Ordinance {
+        issuers: vec!["最高裁判所規則".to_string()],
+        num: 3,
+      },
+    ),
+    (
+      "昭和二十四年人事院規則一四―七",
+      Era::Showa,
+      24,
+      LawNumType::Jinjin {
+        kind: 14,
+        kind_serial_number: 7,
+        amendment_serial_number: 0,
+      },
+    ),
+    (
+      "令和五年人事院規則九―七―六〇",
+      Era::Reiwa,
+      5,
+      LawNumType::Jinjin {
+        kind: 9,
+        kind_serial_number: 7,
+        amendment_serial_number: 60,
+      },
+    ),
+    (
+      "平成十三年一月六日内閣総理大臣決定",
+      Era::Heisei,
+      13,
+      LawNumType::PrimeMinisterDecision { month: 1, day: 6 },
+    ),
+  ];
+  for (s, era, year, law_num_type) in lst {
+    let law_num = LawNum::from_str(s).unwrap();
+    assert_eq!(
+      law_num,
+      LawNum {
+        era,
+        year,
+        law_num_type
+      }
+    );
+    assert_eq!(law_num.to_string(), s);
+  }
+  assert!(LawNum::from_str("昭和二十五年法律").is_err());
+  assert!(LawNum::from_str("昭和二十五年通達第一号").is_err());
+  assert!(LawNum::from_str("西暦二十五年法律第一号").is_err());
+}
+
+#[test]
+fn check_law_num_law_id() {
+  let id = LawId::from_str("325AC0000000131").unwrap();
+  let law_num = LawNum::from_str("昭和二十五年法律第百三十一号").unwrap();
+  assert_eq!(LawNum::from_law_id(&id), law_num);
+  assert!(law_num.is_consistent_with(&id));
+  assert_eq!(law_num.to_law_id(), None);
+  assert!(!law_num.is_consistent_with(&LawId::from_str("325AC0000000132").unwrap()));
+  assert!(!law_num.is_consistent_with(&LawId::from_str("325CO0000000131").unwrap()));
+
+  let id = LawId::from_str("321CONSTITUTION").unwrap();
+  let law_num = LawNum::from_str("昭和二十一年憲法").unwrap();
+  assert_eq!(law_num.to_law_id(), Some(id.clone()));
+  assert_eq!(LawNum::from_law_id(&id), law_num);
+
+  let id = LawId::from_str("505M60001024060").unwrap();
+  let law_num = LawNum::from_str("令和五年復興庁・外務省・環境省令第六十号").unwrap();
+  assert!(law_num.is_consistent_with(&id));
+  assert_eq!(law_num.to_law_id(), Some(id.clone()));
+  assert_eq!(LawNum::from_law_id(&id), law_num);
+  assert!(!LawNum::from_str("令和五年外務省・環境省令第六十号")
+    .unwrap()
+    .is_consistent_with(&id));
+
+  let law_num = LawNum::from_str("令和五年経済産業省・環境省令第一号").unwrap();
+  let id = law_num.to_law_id().unwrap();
+  assert_eq!(id.to_string(), "505M60001400001");
+  assert_eq!(
+    id.law_id_type,
+    LawIdType::MinistryOrder {
+      ministry: Ministry::M6(vec![
+        M6Ministry::MinistryOfTheEnvironmentOrdinance,
+        M6Ministry::MinistryOfEconomyAndTradeAndIndustryOrdinance,
+      ]),
+      num: 1
+    }
+  );
+  assert_eq!(
+    LawNum::from(&id).to_string(),
+    "令和五年経済産業省・環境省令第一号"
+  );
+
+  let law_num = LawNum::from_str("昭和二十二年会計検査院規則第一号").unwrap();
+  let id = law_num.to_law_id().unwrap();
+  assert_eq!(id.to_string(), "322R00000001001");
+  assert!(law_num.is_consistent_with(&id));
+  assert_eq!(LawNum::from_law_id(&id), law_num);
+}
+
 /// 法令のデータ
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LawInfo {
@@ -1268,6 +3530,13 @@ pub struct LawInfo {
   pub patch: Vec<LawPatchInfo>,
 }
 
+impl LawInfo {
+  /// 法令番号を解析する
+  pub fn law_num(&self) -> Result<LawNum, LawNumParseError> {
+    LawNum::from_str(&self.num)
+  }
+}
+
 /// 改正法令の情報
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LawPatchInfo {
@@ -1299,21 +3568,39 @@ impl LawPatchInfo {
 }
 
 impl FromStr for LawPatchInfo {
-  type Err = ();
+  type Err = LawIdParseError;
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let sl = s.split('_').collect::<Vec<&str>>();
-    let id_s = sl.first().ok_or(())?;
+    let mut sl = s.split('_');
+    let id_s = sl.next().unwrap_or_default();
     let id = LawId::from_str(id_s)?;
-    let date_s = sl.get(1).ok_or(())?;
-    let y = date_s[0..=3].parse::<usize>().map_err(|_| ())?;
-    let m = date_s[4..=5].parse::<usize>().map_err(|_| ())?;
-    let d = date_s[6..=7].parse::<usize>().map_err(|_| ())?;
-    let patch_date = Date::gen_from_ad(y, m, d);
-    let patch_s = sl.get(2).ok_or(())?;
-    let patch_id = if patch_s == &"000000000000000" {
+    let date_offset = id_s.len() + 1;
+    let date_s = sl.next().ok_or_else(|| LawIdParseError::MissingField {
+      offset: s.len(),
+      field: "patch_date".to_string(),
+    })?;
+    check_id_str(date_s, 8).map_err(|e| match e {
+      LawIdParseError::InvalidLength { .. } => LawIdParseError::InvalidNumber {
+        offset: date_offset,
+        found: date_s.to_string(),
+      },
+      e => e.shift(date_offset),
+    })?;
+    let date_part =
+      |start, end| parse_id_number(date_s, start, end, 10).map_err(|e| e.shift(date_offset));
+    let (y, m, d) = (date_part(0, 4)?, date_part(4, 6)?, date_part(6, 8)?);
+    let patch_date = Date::try_from_ad(y, m, d).map_err(|error| LawIdParseError::InvalidDate {
+      offset: date_offset,
+      error,
+    })?;
+    let patch_offset = date_offset + date_s.len() + 1;
+    let patch_s = sl.next().ok_or_else(|| LawIdParseError::MissingField {
+      offset: s.len(),
+      field: "patch_id".to_string(),
+    })?;
+    let patch_id = if patch_s == "000000000000000" {
       None
     } else {
-      Some(LawId::from_str(patch_s)?)
+      Some(LawId::from_str(patch_s).map_err(|e| e.shift(patch_offset))?)
     };
     Ok(LawPatchInfo {
       id,
@@ -1322,3 +3609,379 @@ impl FromStr for LawPatchInfo {
     })
   }
 }
+
+#[test]
+fn check_law_id_parse_error() {
+  assert_eq!(
+    LawId::from_str("325AC00000"),
+    Err(LawIdParseError::InvalidLength {
+      expected: 15,
+      found: 10
+    })
+  );
+  assert_eq!(
+    LawId::from_str("325AC0000000あ"),
+    Err(LawIdParseError::NonAscii { offset: 12 })
+  );
+  assert_eq!(
+    LawId::from_str("925AC0000000131"),
+    Err(LawIdParseError::UnknownEra {
+      offset: 0,
+      found: '9'
+    })
+  );
+  assert_eq!(
+    LawId::from_str("325XX0000000131"),
+    Err(LawIdParseError::UnknownPrefix {
+      offset: 3,
+      found: "XX".to_string()
+    })
+  );
+  assert_eq!(
+    LawId::from_str("325AC0010000131"),
+    Err(LawIdParseError::UnknownRippouType {
+      offset: 5,
+      found: "0010000".to_string()
+    })
+  );
+  assert_eq!(
+    LawId::from_str("325CO00000001+1"),
+    Err(LawIdParseError::InvalidNumber {
+      offset: 12,
+      found: "1+1".to_string()
+    })
+  );
+  assert_eq!(
+    LawId::from_str("505M60000000060"),
+    Ok(LawId {
+      era: Era::Reiwa,
+      year: 5,
+      law_id_type: LawIdType::MinistryOrder {
+        ministry: Ministry::M6(Vec::new()),
+        num: 60
+      }
+    })
+  );
+  assert_eq!(
+    LawId::from_str("505M6000C000060"),
+    Err(LawIdParseError::UnknownMinistryBit { offset: 5, bit: 16 })
+  );
+  assert_eq!(
+    LawId::from_str("326R00000099009"),
+    Err(LawIdParseError::UnknownInstitution {
+      offset: 4,
+      code: 99
+    })
+  );
+  assert_eq!(
+    LawPatchInfo::from_str("325AC0000000131_20231301_000000000000000"),
+    Err(LawIdParseError::InvalidDate {
+      offset: 16,
+      error: DateError::InvalidMonth(13)
+    })
+  );
+  assert_eq!(
+    LawPatchInfo::from_str("325AC0000000131_20230601"),
+    Err(LawIdParseError::MissingField {
+      offset: 24,
+      field: "patch_id".to_string()
+    })
+  );
+  assert_eq!(
+    LawPatchInfo::from_str("325AC0000000131_20230601_505AC000000005X"),
+    Err(LawIdParseError::InvalidNumber {
+      offset: 37,
+      found: "05X".to_string()
+    })
+  );
+}
+
+#[test]
+fn check_law_id_no_panic() {
+  // 固定のシードによる疑似乱数で入力を生成し、どの入力でもpanicしないことを確かめる
+  let alphabet = [
+    "0",
+    "1",
+    "2",
+    "3",
+    "4",
+    "5",
+    "6",
+    "7",
+    "8",
+    "9",
+    "A",
+    "C",
+    "D",
+    "F",
+    "H",
+    "I",
+    "J",
+    "M",
+    "N",
+    "O",
+    "P",
+    "R",
+    "T",
+    "X",
+    "_",
+    "+",
+    "-",
+    " ",
+    "あ",
+    "〇",
+    "é",
+    "\u{0}",
+    "CONSTITUTION",
+    "RJNJ",
+    "RPMD",
+  ];
+  let seeds = [
+    "325M50001000004",
+    "345AC0000000089",
+    "505M60001024060",
+    "326R00000011009",
+    "321CONSTITUTION",
+    "324RJNJ14007000",
+    "413RPMD01060001",
+    "129AC0000000089_20230614_505AC0000000053",
+  ];
+  let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+  let mut next = || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state as usize
+  };
+  for _ in 0..20000 {
+    let mut text = String::new();
+    if next() % 2 == 0 {
+      let seed = seeds[next() % seeds.len()];
+      let chars = seed.chars().collect::<Vec<_>>();
+      let cut = next() % (chars.len() + 1);
+      for (i, c) in chars.iter().enumerate() {
+        if i == cut {
+          text.push_str(alphabet[next() % alphabet.len()]);
+        } else {
+          text.push(*c);
+        }
+      }
+    } else {
+      for _ in 0..(next() % 45) {
+        text.push_str(alphabet[next() % alphabet.len()]);
+      }
+    }
+    let _ = LawIdType::from_str(&text);
+    let _ = LawId::from_str(&text);
+    let _ = LawPatchInfo::from_str(&text);
+    let _ = LawNum::from_str(&text);
+    for end in 0..=text.len() {
+      if let Some(t) = text.get(..end) {
+        let _ = LawId::from_str(t);
+        let _ = LawPatchInfo::from_str(t);
+      }
+    }
+  }
+}
+
+/// 法令の改正履歴
+/// 各版を改正・成立年月日の順に並べて保持する
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "LawHistoryFields")]
+pub struct LawHistory {
+  /// 法令ID
+  pub id: LawId,
+  /// 法令名
+  pub name: String,
+  versions: Vec<LawPatchInfo>,
+}
+
+/// デシリアライズ時に版を並べ直すための型
+#[derive(Deserialize)]
+struct LawHistoryFields {
+  id: LawId,
+  name: String,
+  versions: Vec<LawPatchInfo>,
+}
+
+impl From<LawHistoryFields> for LawHistory {
+  fn from(fields: LawHistoryFields) -> Self {
+    let mut versions = fields.versions;
+    versions.sort_by(|a, b| a.patch_date.cmp(&b.patch_date));
+    Self {
+      id: fields.id,
+      name: fields.name,
+      versions,
+    }
+  }
+}
+
+impl LawHistory {
+  /// 法令のデータから改正履歴を作る
+  /// 改正・成立年月日が同じ版は元の順番を保つ
+  pub fn new(info: &LawInfo) -> Self {
+    let mut versions = info.patch.clone();
+    versions.sort_by(|a, b| a.patch_date.cmp(&b.patch_date));
+    Self {
+      id: info.id.clone(),
+      name: info.name.clone(),
+      versions,
+    }
+  }
+
+  /// 古い順に並んだ全ての版
+  pub fn versions(&self) -> &[LawPatchInfo] {
+    &self.versions
+  }
+
+  /// 最初の版
+  pub fn first(&self) -> Option<&LawPatchInfo> {
+    self.versions.first()
+  }
+
+  /// 最新の版
+  pub fn latest(&self) -> Option<&LawPatchInfo> {
+    self.versions.last()
+  }
+
+  /// 指定した日付の時点の版
+  /// 改正・成立年月日がその日付以前である版のうち最も新しいものになる
+  /// 月や日のない日付では版を決められないため`None`になる
+  pub fn version_at(&self, date: &Date) -> Option<&LawPatchInfo> {
+    if date.month.is_none() || date.day.is_none() {
+      return None;
+    }
+    let i = self.versions.partition_point(|v| &v.patch_date <= date);
+    i.checked_sub(1).map(|i| &self.versions[i])
+  }
+
+  /// 指定した日付の時点の版のファイルパス
+  pub fn file_path_at(&self, date: &Date) -> Option<String> {
+    self.version_at(date).map(|v| v.to_file_path())
+  }
+
+  /// 改正した法令の法令IDを古い順に重複なく返す
+  pub fn amending_laws(&self) -> Vec<&LawId> {
+    let mut lst: Vec<&LawId> = Vec::new();
+    for id in self.versions.iter().filter_map(|v| v.patch_id.as_ref()) {
+      if !lst.contains(&id) {
+        lst.push(id);
+      }
+    }
+    lst
+  }
+
+  /// 指定した法令による改正で作られた版
+  pub fn versions_by(&self, patch_id: &LawId) -> Vec<&LawPatchInfo> {
+    self
+      .versions
+      .iter()
+      .filter(|v| v.patch_id.as_ref() == Some(patch_id))
+      .collect()
+  }
+}
+
+impl From<&LawInfo> for LawHistory {
+  fn from(info: &LawInfo) -> Self {
+    Self::new(info)
+  }
+}
+
+impl LawInfo {
+  /// 改正履歴を得る
+  pub fn history(&self) -> LawHistory {
+    LawHistory::new(self)
+  }
+}
+
+#[test]
+fn check_law_history() {
+  let id = LawId::from_str("325AC0000000131").unwrap();
+  let patch = |y, m, d, patch_id: Option<&str>| LawPatchInfo {
+    id: id.clone(),
+    patch_date: Date::gen_from_ad(y, m, d),
+    patch_id: patch_id.map(|s| LawId::from_str(s).unwrap()),
+  };
+  let info = LawInfo {
+    date: Date::new(Era::Showa, 25, Some(5), Some(2)),
+    name: "テスト法".to_string(),
+    num: "昭和二十五年法律第百三十一号".to_string(),
+    id: id.clone(),
+    patch: vec![
+      patch(2015, 4, 1, Some("426AC0000000069")),
+      patch(1950, 5, 2, None),
+      patch(2020, 4, 1, Some("501AC0000000002")),
+      patch(2016, 4, 1, Some("426AC0000000069")),
+    ],
+  };
+  let history = info.history();
+  assert_eq!(
+    history
+      .versions()
+      .iter()
+      .map(|v| v.patch_date.get_ad())
+      .collect::<Vec<_>>(),
+    vec![1950, 2015, 2016, 2020]
+  );
+  assert_eq!(history.first().unwrap().patch_id, None);
+  assert_eq!(
+    history.latest().unwrap().patch_id,
+    Some(LawId::from_str("501AC0000000002").unwrap())
+  );
+
+  assert_eq!(history.version_at(&Date::gen_from_ad(1950, 5, 1)), None);
+  assert_eq!(
+    history.version_at(&Date::gen_from_ad(1950, 5, 2)),
+    Some(&patch(1950, 5, 2, None))
+  );
+  assert_eq!(
+    history.version_at(&Date::gen_from_ad(2015, 3, 31)),
+    Some(&patch(1950, 5, 2, None))
+  );
+  assert_eq!(
+    history.version_at(&Date::gen_from_ad(2015, 4, 1)),
+    Some(&patch(2015, 4, 1, Some("426AC0000000069")))
+  );
+  assert_eq!(
+    history.version_at(&Date::new(Era::Heisei, 27, None, None)),
+    None
+  );
+  assert_eq!(
+    history.version_at(&Date::new(Era::Heisei, 27, Some(4), None)),
+    None
+  );
+  assert_eq!(
+    history.file_path_at(&Date::gen_from_ad(2023, 1, 1)),
+    Some("325AC0000000131_20200401_501AC0000000002".to_string())
+  );
+
+  assert_eq!(
+    history.amending_laws(),
+    vec![
+      &LawId::from_str("426AC0000000069").unwrap(),
+      &LawId::from_str("501AC0000000002").unwrap()
+    ]
+  );
+  assert_eq!(
+    history
+      .versions_by(&LawId::from_str("426AC0000000069").unwrap())
+      .len(),
+    2
+  );
+
+  let json = serde_json::to_string(&history).unwrap();
+  assert_eq!(serde_json::from_str::<LawHistory>(&json).unwrap(), history);
+  let mut value = serde_json::to_value(&history).unwrap();
+  value["versions"].as_array_mut().unwrap().reverse();
+  assert_eq!(
+    serde_json::from_value::<LawHistory>(value).unwrap(),
+    history
+  );
+
+  let empty = LawHistory::new(&LawInfo {
+    patch: Vec::new(),
+    ..info
+  });
+  assert_eq!(empty.version_at(&Date::gen_from_ad(2023, 1, 1)), None);
+  assert!(empty.amending_laws().is_empty());
+}
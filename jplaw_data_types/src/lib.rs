@@ -2,7 +2,10 @@
 #![recursion_limit = "256"]
 pub mod analysis;
 pub mod article;
+pub mod citation;
 pub mod jorei;
 pub mod law;
 pub mod listup;
+pub mod normalize;
+pub mod numeral;
 pub mod precedent;
@@ -0,0 +1,315 @@
+//! 条文や判決文の文字列の表記ゆれをそろえる
+//!
+//! NFKCに近い変換を行うが、「」や（）のような法令で使う括弧は全角のまま残す
+
+use serde::{Deserialize, Serialize};
+
+/// 全角・半角の変換方法
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Width {
+  /// 変換しない
+  Keep,
+  /// 半角にする
+  Half,
+  /// 全角にする
+  Full,
+}
+
+/// 空白の扱い
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WhitespaceStyle {
+  /// 変換しない
+  Keep,
+  /// 連続する空白を最初の一つにまとめる
+  Collapse,
+  /// 空白を全て取り除く
+  Remove,
+}
+
+/// 文字列をそろえるときの設定
+/// 改行は常にそのまま残す
+/// `Default`は何も変換しない
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizeOptions {
+  /// 「２５」のような数字
+  pub digits: Width,
+  /// 「ＡＢＣ」のようなラテン文字
+  pub alphabets: Width,
+  /// 括弧と空白以外の「！」「％」のような記号
+  pub symbols: Width,
+  /// 「(」「｢」のような半角の括弧を「（」「「」のような全角の括弧にするかどうか
+  pub full_width_brackets: bool,
+  /// 「ｶﾞ」のような半角カタカナを「ガ」のような全角カタカナにするかどうか
+  pub full_width_kana: bool,
+  /// 「國」のような旧字体を「国」のような新字体にするかどうか
+  pub shinjitai: bool,
+  /// 空白
+  pub whitespace: WhitespaceStyle,
+}
+
+impl Default for NormalizeOptions {
+  fn default() -> Self {
+    Self {
+      digits: Width::Keep,
+      alphabets: Width::Keep,
+      symbols: Width::Keep,
+      full_width_brackets: false,
+      full_width_kana: false,
+      shinjitai: false,
+      whitespace: WhitespaceStyle::Keep,
+    }
+  }
+}
+
+impl NormalizeOptions {
+  /// 法令の表記に合わせる設定
+  /// 数字やラテン文字はそのままにし、半角の括弧とカタカナを全角にして空白をまとめる
+  pub fn law() -> Self {
+    Self {
+      full_width_brackets: true,
+      full_width_kana: true,
+      whitespace: WhitespaceStyle::Collapse,
+      ..Default::default()
+    }
+  }
+
+  /// 条文と判決文の間などで文字列を照合するための設定
+  /// 数字・ラテン文字・記号を半角に、括弧とカタカナを全角に、旧字体を新字体にそろえ、空白を取り除く
+  pub fn matching() -> Self {
+    Self {
+      digits: Width::Half,
+      alphabets: Width::Half,
+      symbols: Width::Half,
+      full_width_brackets: true,
+      full_width_kana: true,
+      shinjitai: true,
+      whitespace: WhitespaceStyle::Remove,
+    }
+  }
+}
+
+/// 半角カタカナと対応する全角の文字
+const HALF_WIDTH_KANA: &str = "｡､･ｦｧｨｩｪｫｬｭｮｯｰｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜﾝﾞﾟ";
+const FULL_WIDTH_KANA: &str = "。、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+
+/// 旧字体と新字体の組
+const KYUJITAI: &[(char, char)] = &[
+  ('國', '国'),
+  ('學', '学'),
+  ('會', '会'),
+  ('與', '与'),
+  ('舊', '旧'),
+  ('條', '条'),
+  ('體', '体'),
+  ('據', '拠'),
+  ('權', '権'),
+  ('證', '証'),
+  ('區', '区'),
+  ('廣', '広'),
+  ('應', '応'),
+  ('當', '当'),
+  ('對', '対'),
+  ('關', '関'),
+  ('辯', '弁'),
+  ('辨', '弁'),
+  ('瓣', '弁'),
+  ('處', '処'),
+  ('圖', '図'),
+  ('團', '団'),
+  ('實', '実'),
+  ('變', '変'),
+  ('價', '価'),
+  ('賣', '売'),
+  ('讀', '読'),
+  ('續', '続'),
+  ('經', '経'),
+  ('縣', '県'),
+  ('廳', '庁'),
+  ('遞', '逓'),
+  ('鐵', '鉄'),
+  ('號', '号'),
+  ('參', '参'),
+  ('數', '数'),
+  ('營', '営'),
+  ('譯', '訳'),
+  ('擔', '担'),
+  ('擧', '挙'),
+  ('氣', '気'),
+  ('效', '効'),
+  ('總', '総'),
+  ('稅', '税'),
+  ('收', '収'),
+  ('乘', '乗'),
+  ('發', '発'),
+  ('歸', '帰'),
+  ('齡', '齢'),
+  ('聽', '聴'),
+  ('醫', '医'),
+  ('藥', '薬'),
+  ('勞', '労'),
+  ('兒', '児'),
+  ('戰', '戦'),
+  ('單', '単'),
+  ('傳', '伝'),
+  ('邊', '辺'),
+  ('畫', '画'),
+  ('擴', '拡'),
+  ('盡', '尽'),
+  ('獨', '独'),
+  ('爲', '為'),
+  ('兩', '両'),
+  ('拂', '払'),
+  ('轉', '転'),
+  ('辭', '辞'),
+  ('禮', '礼'),
+  ('齒', '歯'),
+  ('將', '将'),
+  ('狀', '状'),
+  ('黨', '党'),
+  ('鑛', '鉱'),
+  ('觸', '触'),
+  ('險', '険'),
+  ('驗', '験'),
+  ('檢', '検'),
+  ('澤', '沢'),
+  ('擇', '択'),
+  ('寫', '写'),
+  ('濟', '済'),
+  ('劑', '剤'),
+  ('惠', '恵'),
+  ('榮', '栄'),
+  ('衞', '衛'),
+  ('圓', '円'),
+  ('假', '仮'),
+  ('缺', '欠'),
+  ('餘', '余'),
+  ('豫', '予'),
+  ('來', '来'),
+  ('廢', '廃'),
+  ('從', '従'),
+  ('惡', '悪'),
+  ('屆', '届'),
+  ('卷', '巻'),
+];
+
+fn is_whitespace(c: char) -> bool {
+  matches!(c, ' ' | '\t' | '\u{3000}' | '\u{00A0}')
+}
+
+fn is_bracket(c: char) -> bool {
+  "()[]{}（）［］｛｝".contains(c)
+}
+
+/// ASCIIの文字と全角の文字を変換する
+fn convert_width(c: char, width: Width) -> char {
+  let offset = '！' as u32 - '!' as u32;
+  match width {
+    Width::Keep => c,
+    Width::Half if ('！'..='～').contains(&c) => char::from_u32(c as u32 - offset).unwrap_or(c),
+    Width::Full if ('!'..='~').contains(&c) => char::from_u32(c as u32 + offset).unwrap_or(c),
+    _ => c,
+  }
+}
+
+/// 半角カタカナを全角にし、続く濁点・半濁点を合成する
+fn full_width_kana(c: char, next: Option<char>) -> Option<(char, bool)> {
+  let i = HALF_WIDTH_KANA.chars().position(|k| k == c)?;
+  let full = FULL_WIDTH_KANA.chars().nth(i)?;
+  let composed = match next {
+    Some('ﾞ') if full == 'ウ' => Some('ヴ'),
+    Some('ﾞ') if "カキクケコサシスセソタチツテトハヒフヘホ".contains(full) => {
+      char::from_u32(full as u32 + 1)
+    }
+    Some('ﾟ') if "ハヒフヘホ".contains(full) => char::from_u32(full as u32 + 2),
+    _ => None,
+  };
+  Some(composed.map_or((full, false), |c| (c, true)))
+}
+
+/// 設定に従って文字列の表記をそろえる
+pub fn normalize(s: &str, options: &NormalizeOptions) -> String {
+  let mut text = String::with_capacity(s.len());
+  let mut chars = s.chars().peekable();
+  let mut prev_whitespace = false;
+  while let Some(c) = chars.next() {
+    if is_whitespace(c) {
+      match options.whitespace {
+        WhitespaceStyle::Keep => text.push(c),
+        WhitespaceStyle::Collapse if !prev_whitespace => text.push(c),
+        _ => (),
+      }
+      prev_whitespace = true;
+      continue;
+    }
+    prev_whitespace = false;
+    let c = if options.full_width_kana {
+      match full_width_kana(c, chars.peek().copied()) {
+        Some((full, composed)) => {
+          if composed {
+            chars.next();
+          }
+          full
+        }
+        None => c,
+      }
+    } else {
+      c
+    };
+    let half = convert_width(c, Width::Half);
+    let c = if half.is_ascii_digit() {
+      convert_width(c, options.digits)
+    } else if half.is_ascii_alphabetic() {
+      convert_width(c, options.alphabets)
+    } else if is_bracket(c) {
+      if options.full_width_brackets {
+        convert_width(c, Width::Full)
+      } else {
+        c
+      }
+    } else if options.full_width_brackets && c == '｢' {
+      '「'
+    } else if options.full_width_brackets && c == '｣' {
+      '」'
+    } else {
+      convert_width(c, options.symbols)
+    };
+    let c = if options.shinjitai {
+      KYUJITAI
+        .iter()
+        .find(|(old, _)| *old == c)
+        .map_or(c, |(_, new)| *new)
+    } else {
+      c
+    };
+    text.push(c);
+  }
+  text
+}
+
+#[test]
+fn check_normalize() {
+  let s = "第２５条（ﾃｽﾄ）  ＡＢＣ(ｶﾞｯｺｳ)　國の權限！";
+  assert_eq!(normalize(s, &NormalizeOptions::default()), s);
+  assert_eq!(
+    normalize(s, &NormalizeOptions::law()),
+    "第２５条（テスト） ＡＢＣ（ガッコウ）　國の權限！"
+  );
+  assert_eq!(
+    normalize(s, &NormalizeOptions::matching()),
+    "第25条（テスト）ABC（ガッコウ）国の権限!"
+  );
+  let options = NormalizeOptions {
+    digits: Width::Full,
+    ..Default::default()
+  };
+  assert_eq!(normalize("第25条\n第3項", &options), "第２５条\n第３項");
+  assert_eq!(
+    normalize("ﾊﾟﾋﾟｳﾞ｢ｱ｣", &NormalizeOptions::law()),
+    "パピヴ「ア」"
+  );
+  let options = NormalizeOptions {
+    full_width_kana: true,
+    ..Default::default()
+  };
+  assert_eq!(normalize("｢ｱ｣､", &options), "｢ア｣、");
+}
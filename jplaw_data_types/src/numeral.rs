@@ -0,0 +1,353 @@
+//! 漢数字・大字・全角数字・ローマ数字と数との変換
+//!
+//! 「百三十一」のような十・百・千・万を使う表記と「二〇二三」のような〇を使う位取りの表記の両方を扱う
+
+use japanese_law_xml_schema::article_number::ArticleNumber;
+
+/// 漢数字の一の位の文字
+const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// 万以上の位の文字と10の指数
+const LARGE_UNITS: [(char, u32); 4] = [('京', 16), ('兆', 12), ('億', 8), ('万', 4)];
+
+/// 算用数字・全角数字・漢数字・大字の一文字を数にする
+pub fn digit_value(c: char) -> Option<usize> {
+  match c {
+    '0'..='9' => Some(c as usize - '0' as usize),
+    '０'..='９' => Some(c as usize - '０' as usize),
+    '〇' | '零' => Some(0),
+    '一' | '壱' | '壹' => Some(1),
+    '二' | '弐' | '貳' => Some(2),
+    '三' | '参' | '參' => Some(3),
+    '四' | '肆' => Some(4),
+    '五' | '伍' => Some(5),
+    '六' | '陸' => Some(6),
+    '七' | '漆' => Some(7),
+    '八' | '捌' => Some(8),
+    '九' | '玖' => Some(9),
+    _ => None,
+  }
+}
+
+/// 十・百・千の位を表す文字を数にする
+fn small_unit_value(c: char) -> Option<usize> {
+  match c {
+    '十' | '拾' => Some(10),
+    '百' | '佰' => Some(100),
+    '千' | '仟' | '阡' => Some(1000),
+    _ => None,
+  }
+}
+
+/// 万以上の位を表す文字の10の指数
+fn large_unit_exp(c: char) -> Option<u32> {
+  match c {
+    '萬' => Some(4),
+    _ => LARGE_UNITS.iter().find(|(u, _)| *u == c).map(|(_, e)| *e),
+  }
+}
+
+/// 数の表記に使われる文字かどうか
+pub fn is_numeral(c: char) -> bool {
+  digit_value(c).is_some() || small_unit_value(c).is_some() || large_unit_exp(c).is_some()
+}
+
+/// 算用数字・全角数字・漢数字・大字で書かれた数を読む
+/// 漢数字は「百三十一」のような表記と「二〇二三」のような〇を使う位取りの表記の両方に対応する
+pub fn parse_number_ja(s: &str) -> Option<usize> {
+  if s.is_empty() {
+    return None;
+  }
+  if s
+    .chars()
+    .all(|c| small_unit_value(c).is_none() && large_unit_exp(c).is_none())
+  {
+    return s.chars().try_fold(0_usize, |n, c| {
+      n.checked_mul(10)?.checked_add(digit_value(c)?)
+    });
+  }
+  let mut total = 0_usize;
+  let mut last_large_exp = u32::MAX;
+  let mut section = 0_usize;
+  let mut last_unit = usize::MAX;
+  let mut digit = None;
+  for c in s.chars() {
+    if let Some(exp) = large_unit_exp(c) {
+      let n = section + digit.take().unwrap_or(0);
+      if n == 0 || exp >= last_large_exp {
+        return None;
+      }
+      total = total.checked_add(n.checked_mul(10_usize.checked_pow(exp)?)?)?;
+      last_large_exp = exp;
+      section = 0;
+      last_unit = usize::MAX;
+    } else if let Some(unit) = small_unit_value(c) {
+      if unit >= last_unit {
+        return None;
+      }
+      last_unit = unit;
+      section += digit.take().unwrap_or(1) * unit;
+    } else {
+      if digit.is_some() {
+        return None;
+      }
+      digit = Some(digit_value(c).filter(|n| *n != 0)?);
+    }
+  }
+  total.checked_add(section + digit.unwrap_or(0))
+}
+
+#[test]
+fn check_parse_number_ja() {
+  assert_eq!(parse_number_ja("百三十一"), Some(131));
+  assert_eq!(parse_number_ja("二十五"), Some(25));
+  assert_eq!(parse_number_ja("千九百"), Some(1900));
+  assert_eq!(parse_number_ja("二〇二三"), Some(2023));
+  assert_eq!(parse_number_ja("２５"), Some(25));
+  assert_eq!(parse_number_ja("10"), Some(10));
+  assert_eq!(parse_number_ja("一万二千"), Some(12000));
+  assert_eq!(parse_number_ja("十百"), None);
+  assert_eq!(parse_number_ja("二三十"), None);
+  assert_eq!(parse_number_ja(""), None);
+  assert_eq!(parse_number_ja("壱万弐阡参拾"), Some(12030));
+  assert_eq!(parse_number_ja("一億二千万"), Some(120_000_000));
+  assert_eq!(parse_number_ja("一万一億"), None);
+  assert_eq!(parse_number_ja("万"), None);
+}
+
+fn push_under_10000(n: usize, explicit_one: bool, s: &mut String) {
+  for (unit, c) in [(1000, '千'), (100, '百'), (10, '十')] {
+    let d = n / unit % 10;
+    if d > 1 || (d == 1 && explicit_one) {
+      s.push(DIGITS[d]);
+    }
+    if d > 0 {
+      s.push(c);
+    }
+  }
+  let d = n % 10;
+  if d > 0 {
+    s.push(DIGITS[d]);
+  }
+}
+
+fn to_kansuji_with(n: usize, explicit_one: bool) -> String {
+  if n == 0 {
+    return DIGITS[0].to_string();
+  }
+  let mut s = String::new();
+  let mut rest = n;
+  for (c, exp) in LARGE_UNITS {
+    let Some(unit) = 10_usize.checked_pow(exp) else {
+      continue;
+    };
+    let d = rest / unit;
+    if d > 0 {
+      push_under_10000(d, explicit_one, &mut s);
+      s.push(c);
+    }
+    rest %= unit;
+  }
+  push_under_10000(rest, explicit_one, &mut s);
+  s
+}
+
+/// 数を「百三十一」のような漢数字にする
+pub fn to_kansuji(n: usize) -> String {
+  to_kansuji_with(n, false)
+}
+
+/// 数を「一四」のような位取りの漢数字にする
+pub fn to_kansuji_positional(n: usize) -> String {
+  n.to_string()
+    .chars()
+    .filter_map(|c| digit_value(c).map(|d| DIGITS[d]))
+    .collect()
+}
+
+/// 数を「壱万弐千参拾」のような大字にする
+/// 壱・弐・参・拾のみを大字にし、十の位などの一も省略しない
+pub fn to_daiji(n: usize) -> String {
+  to_kansuji_with(n, true)
+    .chars()
+    .map(|c| match c {
+      '一' => '壱',
+      '二' => '弐',
+      '三' => '参',
+      '十' => '拾',
+      _ => c,
+    })
+    .collect()
+}
+
+#[test]
+fn check_to_kansuji() {
+  assert_eq!(to_kansuji(131), "百三十一");
+  assert_eq!(to_kansuji(25), "二十五");
+  assert_eq!(to_kansuji(1900), "千九百");
+  assert_eq!(to_kansuji(10), "十");
+  assert_eq!(to_kansuji(12000), "一万二千");
+  assert_eq!(to_kansuji(110_000_000), "一億千万");
+  assert_eq!(to_kansuji_positional(2023), "二〇二三");
+  assert_eq!(to_daiji(12030), "壱万弐千参拾");
+  for n in 0..30000 {
+    assert_eq!(parse_number_ja(&to_kansuji(n)), Some(n));
+  }
+}
+
+#[test]
+fn check_kansuji_round_trip() {
+  let large = (0..2000_usize)
+    .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15_u64 as usize))
+    .chain([usize::MAX, usize::MAX / 10, 10_000, 100_000_000]);
+  for n in (0..30000).chain(large) {
+    assert_eq!(parse_number_ja(&to_kansuji(n)), Some(n));
+    assert_eq!(parse_number_ja(&to_daiji(n)), Some(n));
+    assert_eq!(parse_number_ja(&to_kansuji_positional(n)), Some(n));
+    assert_eq!(
+      parse_number_ja(&to_full_width_digits(&n.to_string())),
+      Some(n)
+    );
+  }
+}
+
+/// 全角数字を半角数字にする
+/// 数字以外の文字はそのまま残す
+pub fn to_half_width_digits(s: &str) -> String {
+  s.chars()
+    .map(|c| match c {
+      '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap_or(c),
+      _ => c,
+    })
+    .collect()
+}
+
+/// 半角数字を全角数字にする
+/// 数字以外の文字はそのまま残す
+pub fn to_full_width_digits(s: &str) -> String {
+  s.chars()
+    .map(|c| match c {
+      '0'..='9' => char::from_u32(c as u32 - '0' as u32 + '０' as u32).unwrap_or(c),
+      _ => c,
+    })
+    .collect()
+}
+
+#[test]
+fn check_full_width_digits() {
+  assert_eq!(to_half_width_digits("第１２条（３）"), "第12条（3）");
+  assert_eq!(
+    to_full_width_digits("平成3年(ワ)第12号"),
+    "平成３年(ワ)第１２号"
+  );
+  for n in 0..10000 {
+    let s = format!("第{n}号");
+    assert_eq!(to_half_width_digits(&to_full_width_digits(&s)), s);
+  }
+}
+
+/// 「二十七の二」や「27の2」のような枝番号付きの番号を読む
+pub fn parse_article_number_ja(s: &str) -> Option<ArticleNumber> {
+  let mut numbers = s.split('の').map(parse_number_ja);
+  Some(ArticleNumber {
+    base_number: numbers.next()??,
+    eda_numbers: numbers.collect::<Option<Vec<_>>>()?,
+    range_end_numbers: Vec::new(),
+  })
+}
+
+/// 番号を「二十七の二」のような枝番号付きの漢数字にする
+pub fn article_number_to_kansuji(num: &ArticleNumber) -> String {
+  let mut s = to_kansuji(num.base_number);
+  for n in num.eda_numbers.iter() {
+    s.push('の');
+    s.push_str(&to_kansuji(*n));
+  }
+  s
+}
+
+#[test]
+fn check_article_number_ja() {
+  let num = ArticleNumber::from_num_str("27_2_3").unwrap();
+  assert_eq!(parse_article_number_ja("二十七の二の三"), Some(num.clone()));
+  assert_eq!(parse_article_number_ja("２７の２の３"), Some(num.clone()));
+  assert_eq!(article_number_to_kansuji(&num), "二十七の二の三");
+  assert_eq!(parse_article_number_ja("二十七の"), None);
+  for base_number in 1..200 {
+    for eda in 0..4 {
+      let num = ArticleNumber {
+        base_number,
+        eda_numbers: (2..eda + 2).collect(),
+        range_end_numbers: Vec::new(),
+      };
+      assert_eq!(
+        parse_article_number_ja(&article_number_to_kansuji(&num)),
+        Some(num)
+      );
+    }
+  }
+}
+
+/// 「ii」や「ｉｖ」のような小文字のローマ数字を読む
+pub fn parse_roman(s: &str) -> Option<usize> {
+  let value = |c: char| match c {
+    'i' | 'ｉ' => Some(1),
+    'v' | 'ｖ' => Some(5),
+    'x' | 'ｘ' => Some(10),
+    'l' | 'ｌ' => Some(50),
+    'c' | 'ｃ' => Some(100),
+    _ => None,
+  };
+  let values = s.chars().map(value).collect::<Option<Vec<usize>>>()?;
+  if values.is_empty() {
+    return None;
+  }
+  let mut n = 0;
+  for (i, v) in values.iter().enumerate() {
+    if values.get(i + 1).map_or(false, |next| next > v) {
+      n -= *v as isize;
+    } else {
+      n += *v as isize;
+    }
+  }
+  usize::try_from(n).ok().filter(|n| *n != 0)
+}
+
+/// 数を「xiv」のような小文字のローマ数字にする
+/// 0や400以上の数は`None`になる
+pub fn to_roman(n: usize) -> Option<String> {
+  if n == 0 || n >= 400 {
+    return None;
+  }
+  let mut s = String::new();
+  let mut rest = n;
+  for (v, r) in [
+    (100, "c"),
+    (90, "xc"),
+    (50, "l"),
+    (40, "xl"),
+    (10, "x"),
+    (9, "ix"),
+    (5, "v"),
+    (4, "iv"),
+    (1, "i"),
+  ] {
+    while rest >= v {
+      s.push_str(r);
+      rest -= v;
+    }
+  }
+  Some(s)
+}
+
+#[test]
+fn check_roman() {
+  assert_eq!(parse_roman("ii"), Some(2));
+  assert_eq!(parse_roman("ｉｖ"), Some(4));
+  assert_eq!(parse_roman("xiv"), Some(14));
+  assert_eq!(parse_roman(""), None);
+  assert_eq!(to_roman(14).as_deref(), Some("xiv"));
+  for n in 1..400 {
+    assert_eq!(parse_roman(&to_roman(n).unwrap()), Some(n));
+  }
+}
@@ -1,8 +1,17 @@
 //! 裁判例ページのデータ
 //!
 
-use crate::law::Date;
+use crate::{
+  article::{cmp_article_number, ArticleIndex, TextIndex},
+  citation::{parse_citation, CitationItem, CitationLevel, CitationNumber},
+  law::{era_name, split_era_prefix, Date, LawInfo},
+  listup::AbbInfo,
+  numeral::{is_numeral, parse_number_ja, to_half_width_digits},
+};
+use japanese_law_xml_schema::{article_number::ArticleNumber, law::Era};
 use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap, fmt::Display, str::FromStr};
+use thiserror::Error;
 
 /// 裁判の種類
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
@@ -43,6 +52,16 @@ impl PrecedentInfo {
       self.case_number, self.court_name, self.trial_type, self.lawsuit_id
     )
   }
+
+  /// 事件番号を解析する
+  pub fn parse_case_number(&self) -> Result<CaseNumber, CaseNumberParseError> {
+    self.case_number.parse()
+  }
+
+  /// 裁判所・部・法廷名を解析する
+  pub fn parse_court(&self) -> Result<Court, CourtParseError> {
+    self.court_name.parse()
+  }
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
@@ -106,3 +125,944 @@ pub struct PrecedentData {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub contents: Option<String>,
 }
+
+impl PrecedentData {
+  /// 事件番号を解析する
+  pub fn parse_case_number(&self) -> Result<CaseNumber, CaseNumberParseError> {
+    self.case_number.parse()
+  }
+
+  /// 裁判所・部・法廷名を解析する
+  pub fn parse_court(&self) -> Result<Court, CourtParseError> {
+    self.court_name.parse()
+  }
+
+  /// 原審裁判所名を解析する
+  pub fn parse_original_court(&self) -> Option<Result<Court, CourtParseError>> {
+    self.original_court_name.as_ref().map(|s| s.parse())
+  }
+
+  /// 参照法条を解析する
+  pub fn parse_ref_law(&self, resolver: &LawNameResolver) -> Option<RefLawList> {
+    self.ref_law.as_ref().map(|s| parse_ref_law(s, resolver))
+  }
+
+  /// 原審事件番号を解析する
+  pub fn parse_original_case_number(&self) -> Option<Result<CaseNumber, CaseNumberParseError>> {
+    self.original_case_number.as_ref().map(|s| s.parse())
+  }
+}
+
+/// 事件の分野
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CaseField {
+  /// 民事
+  Civil,
+  /// 刑事
+  Criminal,
+  /// 行政
+  Administrative,
+}
+
+impl Display for CaseField {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let s = match self {
+      CaseField::Civil => "民事",
+      CaseField::Criminal => "刑事",
+      CaseField::Administrative => "行政",
+    };
+    write!(f, "{s}")
+  }
+}
+
+/// 手続の段階
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ProceedingStage {
+  /// 第一審の訴訟・公判
+  FirstInstance,
+  /// 控訴
+  Koso,
+  /// 上告
+  Jokoku,
+  /// 上告受理
+  JokokuJuri,
+  /// 抗告
+  Kokoku,
+  /// 特別抗告
+  SpecialKokoku,
+  /// 許可抗告
+  PermittedKokoku,
+  /// 非常上告
+  ExtraordinaryJokoku,
+  /// 仮処分などの保全
+  Preservation,
+  /// 民事執行
+  Execution,
+  /// 破産
+  Bankruptcy,
+  /// その他の雑事件
+  Miscellaneous,
+}
+
+impl Display for ProceedingStage {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let s = match self {
+      ProceedingStage::FirstInstance => "第一審",
+      ProceedingStage::Koso => "控訴",
+      ProceedingStage::Jokoku => "上告",
+      ProceedingStage::JokokuJuri => "上告受理",
+      ProceedingStage::Kokoku => "抗告",
+      ProceedingStage::SpecialKokoku => "特別抗告",
+      ProceedingStage::PermittedKokoku => "許可抗告",
+      ProceedingStage::ExtraordinaryJokoku => "非常上告",
+      ProceedingStage::Preservation => "保全",
+      ProceedingStage::Execution => "執行",
+      ProceedingStage::Bankruptcy => "破産",
+      ProceedingStage::Miscellaneous => "雑",
+    };
+    write!(f, "{s}")
+  }
+}
+
+/// 事件記号から分かる事件の種類
+/// 「刑事上告」「民事控訴」のように表示する
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ProceedingKind {
+  pub field: CaseField,
+  pub stage: ProceedingStage,
+}
+
+impl Display for ProceedingKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}{}", self.field, self.stage)
+  }
+}
+
+/// 知られている事件記号と事件の種類
+const CASE_MARKS: &[(&str, CaseField, ProceedingStage)] = &[
+  // 最高裁判所
+  ("あ", CaseField::Criminal, ProceedingStage::Jokoku),
+  ("し", CaseField::Criminal, ProceedingStage::SpecialKokoku),
+  (
+    "さ",
+    CaseField::Criminal,
+    ProceedingStage::ExtraordinaryJokoku,
+  ),
+  ("す", CaseField::Criminal, ProceedingStage::Miscellaneous),
+  ("オ", CaseField::Civil, ProceedingStage::Jokoku),
+  ("受", CaseField::Civil, ProceedingStage::JokokuJuri),
+  ("ク", CaseField::Civil, ProceedingStage::SpecialKokoku),
+  ("許", CaseField::Civil, ProceedingStage::PermittedKokoku),
+  ("行ツ", CaseField::Administrative, ProceedingStage::Jokoku),
+  (
+    "行ヒ",
+    CaseField::Administrative,
+    ProceedingStage::JokokuJuri,
+  ),
+  (
+    "行ト",
+    CaseField::Administrative,
+    ProceedingStage::SpecialKokoku,
+  ),
+  (
+    "行フ",
+    CaseField::Administrative,
+    ProceedingStage::PermittedKokoku,
+  ),
+  // 高等裁判所
+  ("う", CaseField::Criminal, ProceedingStage::Koso),
+  ("く", CaseField::Criminal, ProceedingStage::Kokoku),
+  ("ネ", CaseField::Civil, ProceedingStage::Koso),
+  ("ツ", CaseField::Civil, ProceedingStage::Jokoku),
+  ("ラ", CaseField::Civil, ProceedingStage::Kokoku),
+  ("行コ", CaseField::Administrative, ProceedingStage::Koso),
+  (
+    "行ケ",
+    CaseField::Administrative,
+    ProceedingStage::FirstInstance,
+  ),
+  ("行ス", CaseField::Administrative, ProceedingStage::Kokoku),
+  // 地方裁判所・簡易裁判所
+  ("わ", CaseField::Criminal, ProceedingStage::FirstInstance),
+  ("ろ", CaseField::Criminal, ProceedingStage::FirstInstance),
+  ("ワ", CaseField::Civil, ProceedingStage::FirstInstance),
+  ("ハ", CaseField::Civil, ProceedingStage::FirstInstance),
+  ("レ", CaseField::Civil, ProceedingStage::Koso),
+  ("ヨ", CaseField::Civil, ProceedingStage::Preservation),
+  ("ヲ", CaseField::Civil, ProceedingStage::Execution),
+  ("ヌ", CaseField::Civil, ProceedingStage::Execution),
+  ("フ", CaseField::Civil, ProceedingStage::Bankruptcy),
+  ("モ", CaseField::Civil, ProceedingStage::Miscellaneous),
+  (
+    "行ウ",
+    CaseField::Administrative,
+    ProceedingStage::FirstInstance,
+  ),
+  (
+    "行ク",
+    CaseField::Administrative,
+    ProceedingStage::Miscellaneous,
+  ),
+];
+
+/// 事件番号の解析時のエラー
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaseNumberParseError {
+  /// 元号が読めない
+  #[error("unknown era: {0}")]
+  UnknownEra(String),
+  /// 年が読めない
+  #[error("invalid year: {0}")]
+  InvalidYear(String),
+  /// 括弧で囲まれた事件記号が無い
+  #[error("missing case mark: {0}")]
+  MissingMark(String),
+  /// 番号が読めない
+  #[error("invalid serial number: {0}")]
+  InvalidSerial(String),
+}
+
+/// 「令和4年(あ)第1234号」のような事件番号
+/// 文字列としてシリアライズする
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CaseNumber {
+  pub era: Era,
+  pub year: usize,
+  /// 「あ」「行ケ」のような括弧の中の事件記号
+  pub mark: String,
+  /// 番号
+  pub serial: usize,
+}
+
+impl CaseNumber {
+  /// 事件記号から分かる事件の種類
+  /// 知らない事件記号の場合は`None`になる
+  pub fn kind(&self) -> Option<ProceedingKind> {
+    CASE_MARKS
+      .iter()
+      .find(|(mark, _, _)| *mark == self.mark)
+      .map(|(_, field, stage)| ProceedingKind {
+        field: *field,
+        stage: *stage,
+      })
+  }
+}
+
+/// 「令和4(あ)1234」や「平成三十年（行ケ）第一〇〇〇一号」のような表記を読む
+impl FromStr for CaseNumber {
+  type Err = CaseNumberParseError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = s.trim();
+    let (era, rest) =
+      split_era_prefix(s).ok_or_else(|| CaseNumberParseError::UnknownEra(s.to_string()))?;
+    let missing_mark = || CaseNumberParseError::MissingMark(s.to_string());
+    let open = rest.find(['(', '（']).ok_or_else(missing_mark)?;
+    let year_s = &rest[..open];
+    let year_s = year_s.strip_suffix('年').unwrap_or(year_s);
+    let year = if year_s == "元" {
+      1
+    } else {
+      parse_number_ja(year_s)
+        .filter(|n| *n != 0)
+        .ok_or_else(|| CaseNumberParseError::InvalidYear(year_s.to_string()))?
+    };
+    let rest = &rest[open..];
+    let rest = &rest[rest.chars().next().map_or(0, char::len_utf8)..];
+    let close = rest.find([')', '）']).ok_or_else(missing_mark)?;
+    let mark = rest[..close].trim();
+    if mark.is_empty() {
+      return Err(missing_mark());
+    }
+    let serial_s = rest[close..].chars().skip(1).collect::<String>();
+    let serial_s = serial_s.trim();
+    let serial_s = serial_s.strip_prefix('第').unwrap_or(serial_s);
+    let serial_s = serial_s.strip_suffix('号').unwrap_or(serial_s);
+    let serial = parse_number_ja(serial_s)
+      .ok_or_else(|| CaseNumberParseError::InvalidSerial(serial_s.to_string()))?;
+    Ok(CaseNumber {
+      era,
+      year,
+      mark: mark.to_string(),
+      serial,
+    })
+  }
+}
+
+/// 「令和4年(あ)第1234号」の形で出力する
+/// 1年は「元年」と表記する
+impl Display for CaseNumber {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", era_name(&self.era))?;
+    if self.year == 1 {
+      write!(f, "元年")?;
+    } else {
+      write!(f, "{}年", self.year)?;
+    }
+    write!(f, "({})第{}号", self.mark, self.serial)
+  }
+}
+
+impl TryFrom<String> for CaseNumber {
+  type Error = CaseNumberParseError;
+  fn try_from(s: String) -> Result<Self, Self::Error> {
+    s.parse()
+  }
+}
+
+impl From<CaseNumber> for String {
+  fn from(case_number: CaseNumber) -> Self {
+    case_number.to_string()
+  }
+}
+
+#[test]
+fn check_case_number() {
+  let n = CaseNumber::from_str("令和4(あ)1234").unwrap();
+  assert_eq!(n.era, Era::Reiwa);
+  assert_eq!(n.year, 4);
+  assert_eq!(n.mark, "あ");
+  assert_eq!(n.serial, 1234);
+  assert_eq!(n.to_string(), "令和4年(あ)第1234号");
+  assert_eq!(
+    n.kind(),
+    Some(ProceedingKind {
+      field: CaseField::Criminal,
+      stage: ProceedingStage::Jokoku
+    })
+  );
+  assert_eq!(n.kind().unwrap().to_string(), "刑事上告");
+
+  let n = CaseNumber::from_str("平成30年(行ケ)第10001号").unwrap();
+  assert_eq!(
+    n,
+    CaseNumber::from_str("平成三十年（行ケ）第一〇〇〇一号").unwrap()
+  );
+  assert_eq!(n.kind().unwrap().to_string(), "行政第一審");
+  let n = CaseNumber::from_str("令和元年（ネ）第１２号").unwrap();
+  assert_eq!(n.to_string(), "令和元年(ネ)第12号");
+  assert_eq!(n.kind().unwrap().to_string(), "民事控訴");
+  assert_eq!(CaseNumber::from_str("昭和28(れ)1").unwrap().kind(), None);
+
+  assert!(matches!(
+    CaseNumber::from_str("西暦2020(ワ)1"),
+    Err(CaseNumberParseError::UnknownEra(_))
+  ));
+  assert!(matches!(
+    CaseNumber::from_str("令和2()1"),
+    Err(CaseNumberParseError::MissingMark(_))
+  ));
+  assert!(matches!(
+    CaseNumber::from_str("令和2(ワ)"),
+    Err(CaseNumberParseError::InvalidSerial(_))
+  ));
+
+  let n = CaseNumber::from_str("令和4(受)56").unwrap();
+  let json = serde_json::to_string(&n).unwrap();
+  assert_eq!(json, "\"令和4年(受)第56号\"");
+  assert_eq!(serde_json::from_str::<CaseNumber>(&json).unwrap(), n);
+  assert!(serde_json::from_str::<CaseNumber>("\"不明\"").is_err());
+}
+
+/// 裁判所の種類
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CourtLevel {
+  /// 大審院
+  GreatCourtOfCassation,
+  /// 最高裁判所
+  Supreme,
+  /// 高等裁判所
+  High,
+  /// 地方裁判所
+  District,
+  /// 家庭裁判所
+  Family,
+  /// 簡易裁判所
+  Summary,
+}
+
+impl Display for CourtLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let s = match self {
+      CourtLevel::GreatCourtOfCassation => "大審院",
+      CourtLevel::Supreme => "最高裁判所",
+      CourtLevel::High => "高等裁判所",
+      CourtLevel::District => "地方裁判所",
+      CourtLevel::Family => "家庭裁判所",
+      CourtLevel::Summary => "簡易裁判所",
+    };
+    write!(f, "{s}")
+  }
+}
+
+/// 所在地の後に付く裁判所の種類の表記
+const COURT_LEVEL_NAMES: [(&str, CourtLevel); 8] = [
+  ("高等裁判所", CourtLevel::High),
+  ("地方裁判所", CourtLevel::District),
+  ("家庭裁判所", CourtLevel::Family),
+  ("簡易裁判所", CourtLevel::Summary),
+  ("高裁", CourtLevel::High),
+  ("地裁", CourtLevel::District),
+  ("家裁", CourtLevel::Family),
+  ("簡裁", CourtLevel::Summary),
+];
+
+/// 裁判所の支部
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CourtBranch {
+  /// 「堺支部」のような支部の名前から「支部」を除いたもの
+  Branch(String),
+  /// 東京高等裁判所の特別の支部である知的財産高等裁判所
+  IntellectualProperty,
+}
+
+/// 裁判所名の解析時のエラー
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CourtParseError {
+  /// 裁判所の種類が読めない
+  #[error("unknown court: {0}")]
+  UnknownCourt(String),
+}
+
+/// 「最高裁判所第三小法廷」「大阪地方裁判所堺支部」のような裁判所・支部・法廷
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Court {
+  pub level: CourtLevel,
+  /// 「東京」のような所在地
+  /// 最高裁判所と大審院では`None`になる
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub location: Option<String>,
+  /// 支部
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub branch: Option<CourtBranch>,
+  /// 「第三小法廷」「民事第29部」のような法廷・部
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub bench: Option<String>,
+}
+
+impl Court {
+  /// 法廷・部を除いた裁判所
+  /// 裁判所ごとに判決をまとめるときに使う
+  pub fn without_bench(&self) -> Court {
+    Court {
+      bench: None,
+      ..self.clone()
+    }
+  }
+}
+
+/// 「東京高等裁判所 知的財産高等裁判所」や「大阪地裁 堺支部」のような表記を読む
+/// 空白は取り除き、法廷・部の中の数字は半角にする
+impl FromStr for Court {
+  type Err = CourtParseError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let name = s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    let unknown = || CourtParseError::UnknownCourt(s.trim().to_string());
+    let (level, location, rest) = if let Some(rest) = name
+      .strip_prefix("最高裁判所")
+      .or_else(|| name.strip_prefix("最高裁"))
+    {
+      (CourtLevel::Supreme, None, rest)
+    } else if let Some(rest) = name.strip_prefix("大審院") {
+      (CourtLevel::GreatCourtOfCassation, None, rest)
+    } else if let Some(rest) = name
+      .strip_prefix("知的財産高等裁判所")
+      .or_else(|| name.strip_prefix("知財高裁"))
+    {
+      (CourtLevel::High, Some("東京"), rest)
+    } else {
+      let (i, level_name, level) = COURT_LEVEL_NAMES
+        .iter()
+        .filter_map(|(level_name, level)| name.find(level_name).map(|i| (i, *level_name, *level)))
+        .min_by_key(|(i, _, _)| *i)
+        .ok_or_else(unknown)?;
+      if i == 0 {
+        return Err(unknown());
+      }
+      (level, Some(&name[..i]), &name[i + level_name.len()..])
+    };
+    let is_ip = level == CourtLevel::High && name.starts_with("知");
+    let (branch, rest) = if is_ip {
+      (Some(CourtBranch::IntellectualProperty), rest)
+    } else if let Some(rest) = rest
+      .strip_prefix("知的財産高等裁判所")
+      .or_else(|| rest.strip_prefix("知財高裁"))
+    {
+      (Some(CourtBranch::IntellectualProperty), rest)
+    } else if let Some(i) = rest.find("支部") {
+      (
+        Some(CourtBranch::Branch(rest[..i].to_string())),
+        &rest[i + "支部".len()..],
+      )
+    } else {
+      (None, rest)
+    };
+    let bench = Some(to_half_width_digits(rest)).filter(|s| !s.is_empty());
+    Ok(Court {
+      level,
+      location: location.map(|s| s.to_string()),
+      branch,
+      bench,
+    })
+  }
+}
+
+/// 「大阪地方裁判所堺支部」「知的財産高等裁判所第2部」のように空白を入れずに出力する
+impl Display for Court {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self.branch {
+      Some(CourtBranch::IntellectualProperty) => write!(f, "知的財産高等裁判所")?,
+      branch => {
+        if let Some(location) = &self.location {
+          write!(f, "{location}")?;
+        }
+        write!(f, "{}", self.level)?;
+        if let Some(CourtBranch::Branch(name)) = branch {
+          write!(f, "{name}支部")?;
+        }
+      }
+    }
+    if let Some(bench) = &self.bench {
+      write!(f, "{bench}")?;
+    }
+    Ok(())
+  }
+}
+
+#[test]
+fn check_court() {
+  let c = Court::from_str("最高裁判所第三小法廷").unwrap();
+  assert_eq!(c.level, CourtLevel::Supreme);
+  assert_eq!(c.location, None);
+  assert_eq!(c.bench.as_deref(), Some("第三小法廷"));
+  assert_eq!(c.to_string(), "最高裁判所第三小法廷");
+  assert_eq!(c.without_bench().to_string(), "最高裁判所");
+
+  let c = Court::from_str("東京高等裁判所 知的財産高等裁判所").unwrap();
+  assert_eq!(c.level, CourtLevel::High);
+  assert_eq!(c.location.as_deref(), Some("東京"));
+  assert_eq!(c.branch, Some(CourtBranch::IntellectualProperty));
+  assert_eq!(c.to_string(), "知的財産高等裁判所");
+  assert_eq!(Court::from_str("知的財産高等裁判所").unwrap(), c);
+
+  let c = Court::from_str("大阪地方裁判所　堺支部").unwrap();
+  assert_eq!(c.level, CourtLevel::District);
+  assert_eq!(c.location.as_deref(), Some("大阪"));
+  assert_eq!(c.branch, Some(CourtBranch::Branch("堺".to_string())));
+  assert_eq!(c.bench, None);
+  assert_eq!(c.to_string(), "大阪地方裁判所堺支部");
+
+  let c = Court::from_str("東京地方裁判所 民事第２９部").unwrap();
+  assert_eq!(c.bench.as_deref(), Some("民事第29部"));
+  assert_eq!(c.to_string(), "東京地方裁判所民事第29部");
+  assert_eq!(Court::from_str(&c.to_string()).unwrap(), c);
+
+  let c = Court::from_str("横浜家裁川崎支部").unwrap();
+  assert_eq!(c.level, CourtLevel::Family);
+  assert_eq!(c.to_string(), "横浜家庭裁判所川崎支部");
+  assert_eq!(
+    Court::from_str("大審院 第一民事部").unwrap().level,
+    CourtLevel::GreatCourtOfCassation
+  );
+  assert!(Court::from_str("地方裁判所").is_err());
+  assert!(Court::from_str("東京都").is_err());
+}
+
+/// 法令名や略称から正式な法令名を探す
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LawNameResolver {
+  names: HashMap<String, String>,
+}
+
+impl LawNameResolver {
+  /// 法令の一覧と略称の一覧から作る
+  /// 略称は法令番号で法令と結びつける
+  pub fn new(laws: &[LawInfo], abbs: &[AbbInfo]) -> Self {
+    let mut resolver = Self::default();
+    for law in laws.iter() {
+      resolver.insert(&law.name, &law.name);
+    }
+    for abb in abbs.iter() {
+      if let Some(law) = laws.iter().find(|law| law.num == abb.num) {
+        for name in abb.abbs.iter() {
+          resolver
+            .names
+            .entry(name.clone())
+            .or_insert_with(|| law.name.clone());
+        }
+      }
+    }
+    resolver
+  }
+
+  /// 「民訴法」のような略称を追加する
+  pub fn insert(&mut self, name: &str, law_name: &str) {
+    self.names.insert(name.to_string(), law_name.to_string());
+  }
+
+  /// 正式な法令名
+  pub fn resolve(&self, name: &str) -> Option<&str> {
+    self.names.get(name).map(|s| s.as_str())
+  }
+}
+
+/// 参照法条の中の一つの条文
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefLaw {
+  /// 正式な法令名
+  pub law_name: String,
+  /// 「平成7年法律第91号による改正前のもの」のような法令名の後の括弧書き
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub note: Option<String>,
+  /// 条番号
+  /// 範囲の場合は最初の条番号
+  pub article_number: ArticleNumber,
+  /// 「709条から711条まで」のような範囲の場合は最後の条番号
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub range_end: Option<ArticleNumber>,
+  /// 項や号まで示されている場合はその位置
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub text_index: Option<TextIndex>,
+}
+
+impl RefLaw {
+  /// 本則の条を示しているかどうか
+  /// 範囲の場合は「第七百十条の二」のような枝番号の条も含めて判定する
+  pub fn is_article(&self, index: &ArticleIndex) -> bool {
+    let in_range = match &self.range_end {
+      Some(end) => {
+        cmp_article_number(&self.article_number, &index.article_number) != Ordering::Greater
+          && cmp_article_number(&index.article_number, end) != Ordering::Greater
+      }
+      None => index.article_number == self.article_number,
+    };
+    index.law_name == self.law_name && in_range && !index.is_suppl_provision() && !index.is_appdx()
+  }
+}
+
+/// 参照法条を解析できなかった理由
+#[derive(Debug, Error, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RefLawError {
+  /// 法令名が見つからない
+  #[error("unknown law name: {0}")]
+  UnknownLawName(String),
+  /// 法令名が省略されていて前の法令名も無い
+  #[error("missing law name")]
+  MissingLawName,
+  /// 条番号が読めない
+  #[error("missing article number")]
+  MissingArticle,
+  /// 条番号の後に読めない文字列がある
+  #[error("unparsed text: {0}")]
+  UnparsedText(String),
+  /// 「711条から709条まで」のような始まりと終わりが逆の範囲
+  #[error("reversed range: {0}")]
+  ReversedRange(String),
+}
+
+/// 解析できなかった参照法条の一部
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnresolvedRefLaw {
+  /// 「，」などで区切られた元の文字列
+  pub text: String,
+  pub error: RefLawError,
+}
+
+/// 参照法条の解析結果
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefLawList {
+  /// 書かれた順の条文
+  pub items: Vec<RefLaw>,
+  /// 解析できなかった部分
+  pub unresolved: Vec<UnresolvedRefLaw>,
+}
+
+/// 参照法条の区切り文字
+const REF_LAW_SEPARATORS: [char; 6] = ['，', ',', '、', '；', ';', '\n'];
+
+/// 「709条1項」のように「第」が省略された番号に「第」を補う
+fn insert_dai(s: &str) -> String {
+  let mut text = String::new();
+  let mut prev = None;
+  for (i, c) in s.char_indices() {
+    let starts_number =
+      is_numeral(c) && !prev.map_or(false, |p| p == '第' || p == 'の' || is_numeral(p));
+    if starts_number {
+      let unit = s[i..].chars().find(|c| !is_numeral(*c));
+      if unit.map_or(false, |u| "編章節款目条項号".contains(u)) {
+        text.push('第');
+      }
+    }
+    text.push(c);
+    prev = Some(c);
+  }
+  text
+}
+
+/// 括弧書きを取り除き、その中身と組にする
+fn split_note(s: &str) -> (String, Option<String>) {
+  let mut name = String::new();
+  let mut note = String::new();
+  let mut depth = 0_usize;
+  for c in s.chars() {
+    match c {
+      '（' | '(' => {
+        if depth > 0 {
+          note.push(c);
+        }
+        depth += 1;
+      }
+      '）' | ')' if depth > 0 => {
+        depth -= 1;
+        if depth > 0 {
+          note.push(c);
+        }
+      }
+      _ if depth > 0 => note.push(c),
+      _ => name.push(c),
+    }
+  }
+  (name, Some(note).filter(|s| !s.is_empty()))
+}
+
+/// 参照法条の一つの区切りを読む
+fn parse_ref_law_fragment(
+  fragment: &str,
+  resolver: &LawNameResolver,
+  prev: Option<&RefLaw>,
+) -> Result<Vec<RefLaw>, RefLawError> {
+  // 括弧書きの中の「第91号」のような番号は条番号として扱わない
+  let mut depth = 0_usize;
+  let (law_part, article_part) = fragment
+    .char_indices()
+    .filter(|(_, c)| {
+      match c {
+        '（' | '(' => depth += 1,
+        '）' | ')' => depth = depth.saturating_sub(1),
+        _ => return depth == 0,
+      }
+      false
+    })
+    .map(|(i, _)| i)
+    .find_map(|i| {
+      let citation = parse_citation(&insert_dai(&fragment[i..]))?;
+      let first = match citation.items.first()? {
+        CitationItem::Single(path) => path.components.first()?.level,
+        CitationItem::Range { start, .. } => start.components.first()?.level,
+      };
+      (first >= CitationLevel::Article).then_some((&fragment[..i], &fragment[i..]))
+    })
+    .ok_or(RefLawError::MissingArticle)?;
+  let (name, note) = split_note(law_part);
+  let (law_name, note) = match name.as_str() {
+    "" | "同" | "同法" => {
+      let prev = prev.ok_or(RefLawError::MissingLawName)?;
+      (prev.law_name.clone(), note.or_else(|| prev.note.clone()))
+    }
+    name => (
+      resolver
+        .resolve(name)
+        .ok_or_else(|| RefLawError::UnknownLawName(name.to_string()))?
+        .to_string(),
+      note,
+    ),
+  };
+  let article_text = insert_dai(article_part);
+  let citation = parse_citation(&article_text).ok_or(RefLawError::MissingArticle)?;
+  if citation.span.end != article_text.len() {
+    return Err(RefLawError::UnparsedText(
+      article_text[citation.span.end..].to_string(),
+    ));
+  }
+  let ref_law = |article_number, range_end, text_index| RefLaw {
+    law_name: law_name.clone(),
+    note: note.clone(),
+    article_number,
+    range_end,
+    text_index,
+  };
+  let mut v = Vec::new();
+  for item in citation.items.iter() {
+    match item {
+      CitationItem::Single(path) => {
+        let prev_article = || {
+          prev
+            .filter(|p| p.law_name == law_name)
+            .ok_or(RefLawError::MissingArticle)
+        };
+        let article_number = match path.component(CitationLevel::Article).map(|c| &c.number) {
+          Some(CitationNumber::Number(num)) => Some(num.clone()),
+          Some(CitationNumber::Same) => Some(prev_article()?.article_number.clone()),
+          Some(_) => return Err(RefLawError::UnparsedText(fragment.to_string())),
+          None => None,
+        };
+        let (article_number, text_index) = match article_number {
+          Some(num) => (num, path.text_index()),
+          // 「709条1項，2項」の「2項」のように条が省略された場合は直前の条を使う
+          None => {
+            let prev = prev_article()?;
+            let mut text_index = path.text_index().ok_or(RefLawError::MissingArticle)?;
+            if path.component(CitationLevel::Paragraph).is_none() {
+              if let Some(prev_index) = &prev.text_index {
+                text_index.paragraph = prev_index.paragraph.clone();
+              }
+            }
+            (prev.article_number.clone(), Some(text_index))
+          }
+        };
+        v.push(ref_law(article_number, None, text_index));
+      }
+      // 「709条から711条まで」のような条の範囲は展開せずに範囲のまま残す
+      CitationItem::Range { start, end, .. } => {
+        match (start.article_number(), end.article_number()) {
+          (Some(s), Some(e)) if start.components.len() == 1 && end.components.len() == 1 => {
+            if cmp_article_number(s, e) == Ordering::Greater {
+              return Err(RefLawError::ReversedRange(fragment.to_string()));
+            }
+            v.push(ref_law(s.clone(), Some(e.clone()), None));
+          }
+          _ => return Err(RefLawError::UnparsedText(fragment.to_string())),
+        }
+      }
+    }
+  }
+  Ok(v)
+}
+
+/// 「民法709条，商標法4条1項11号」のような参照法条を読む
+///
+/// 「，」や「、」で区切り、法令名を`resolver`で正式な名前にする。
+/// 法令名が省略された部分は直前の法令名を使う。
+/// 読めなかった部分は処理を中断せずに`unresolved`に記録する。
+pub fn parse_ref_law(text: &str, resolver: &LawNameResolver) -> RefLawList {
+  let mut lst = RefLawList::default();
+  for fragment in text.split(REF_LAW_SEPARATORS) {
+    let fragment = fragment
+      .chars()
+      .filter(|c| !c.is_whitespace())
+      .collect::<String>();
+    if fragment.is_empty() {
+      continue;
+    }
+    match parse_ref_law_fragment(&fragment, resolver, lst.items.last()) {
+      Ok(mut items) => lst.items.append(&mut items),
+      Err(error) => lst.unresolved.push(UnresolvedRefLaw {
+        text: fragment,
+        error,
+      }),
+    }
+  }
+  lst
+}
+
+#[test]
+fn check_parse_ref_law() {
+  use crate::law::LawId;
+  let law = |name: &str, num: &str, id: &str| LawInfo {
+    date: Date::new(Era::Meiji, 29, Some(4), Some(27)),
+    name: name.to_string(),
+    num: num.to_string(),
+    id: LawId::from_str(id).unwrap(),
+    patch: Vec::new(),
+  };
+  let laws = [
+    law("民法", "明治二十九年法律第八十九号", "129AC0000000089"),
+    law("商標法", "昭和三十四年法律第百二十七号", "334AC0000000127"),
+    law("刑法", "明治四十年法律第四十五号", "140AC0000000045"),
+    law("民事訴訟法", "平成八年法律第百九号", "408AC0000000109"),
+  ];
+  let abbs = [AbbInfo {
+    num: "平成八年法律第百九号".to_string(),
+    abbs: vec!["民訴法".to_string()],
+  }];
+  let resolver = LawNameResolver::new(&laws, &abbs);
+  assert_eq!(resolver.resolve("民訴法"), Some("民事訴訟法"));
+
+  let num = |s: &str| ArticleNumber::from_num_str(s).unwrap();
+  let lst = parse_ref_law(
+    "民法709条，715条1項，2項，商標法4条1項11号，民訴法３１８条の２、刑法（平成7年法律第91号による改正前のもの）199条，旧商法266条，憲法",
+    &resolver,
+  );
+  assert_eq!(
+    lst
+      .items
+      .iter()
+      .map(|r| (
+        r.law_name.as_str(),
+        r.article_number.clone(),
+        r.text_index
+          .as_ref()
+          .map(|t| (t.paragraph.clone(), t.items.clone()))
+      ))
+      .collect::<Vec<_>>(),
+    vec![
+      ("民法", num("709"), None),
+      ("民法", num("715"), Some((num("1"), Vec::new()))),
+      ("民法", num("715"), Some((num("2"), Vec::new()))),
+      ("商標法", num("4"), Some((num("1"), vec![Some(num("11"))]))),
+      ("民事訴訟法", num("318_2"), None),
+      ("刑法", num("199"), None),
+    ]
+  );
+  assert_eq!(
+    lst.items[5].note.as_deref(),
+    Some("平成7年法律第91号による改正前のもの")
+  );
+  assert_eq!(
+    lst.unresolved,
+    vec![
+      UnresolvedRefLaw {
+        text: "旧商法266条".to_string(),
+        error: RefLawError::UnknownLawName("旧商法".to_string()),
+      },
+      UnresolvedRefLaw {
+        text: "憲法".to_string(),
+        error: RefLawError::MissingArticle,
+      },
+    ]
+  );
+
+  let index = ArticleIndex {
+    file_id: "129AC0000000089".to_string(),
+    law_name: "民法".to_string(),
+    article_number: num("709"),
+    part_number: None,
+    chapter_number: None,
+    section_number: None,
+    subsection_number: None,
+    division_number: None,
+    suppl_provision_name: None,
+    appdx: None,
+  };
+  assert!(lst.items[0].is_article(&index));
+  assert!(!lst.items[1].is_article(&index));
+
+  let lst = parse_ref_law("民法709条から711条まで，民法711条から709条まで", &resolver);
+  assert_eq!(lst.items.len(), 1);
+  assert_eq!(lst.items[0].article_number, num("709"));
+  assert_eq!(lst.items[0].range_end, Some(num("711")));
+  for (article_number, expected) in [
+    ("708", false),
+    ("709", true),
+    ("710_2", true),
+    ("711", true),
+    ("711_2", false),
+  ] {
+    let index = ArticleIndex {
+      article_number: num(article_number),
+      ..index.clone()
+    };
+    assert_eq!(
+      lst.items[0].is_article(&index),
+      expected,
+      "{article_number}"
+    );
+  }
+  assert_eq!(
+    lst.unresolved,
+    vec![UnresolvedRefLaw {
+      text: "民法711条から709条まで".to_string(),
+      error: RefLawError::ReversedRange("民法711条から709条まで".to_string()),
+    }]
+  );
+}
@@ -0,0 +1,288 @@
+use japanese_law_xml_schema::{article_number::ArticleNumber, law::Law, parse_xml_file};
+use jplaw_data_types::{
+  article::{
+    article_entry_list_from_lawbody, article_list_from_lawbody, text_list_with_source_map,
+    ArticleIndex, StructureKind, TextIndex, TextListOptions,
+  },
+  citation::{parse_citation, CitationResolver, ResolveError, ResolvedCitation, ResolvedLocation},
+};
+
+const MINPOU_XML: &str = concat!(
+  env!("CARGO_MANIFEST_DIR"),
+  "/tests/129AC0000000089_20230614_505AC0000000053.xml"
+);
+
+fn minpou() -> Law {
+  parse_xml_file(MINPOU_XML).unwrap()
+}
+
+fn num(s: &str) -> ArticleNumber {
+  ArticleNumber::from_num_str(s).unwrap()
+}
+
+fn minpou_index_list() -> Vec<ArticleIndex> {
+  let law = minpou();
+  article_list_from_lawbody("129AC0000000089", "民法", &law.law_body)
+    .into_iter()
+    .map(|info| info.article_index)
+    .collect()
+}
+
+#[test]
+fn check_article_index_document_order() {
+  let lst = minpou_index_list();
+  let mut sorted = lst.clone();
+  sorted.sort();
+  assert_eq!(lst, sorted);
+  let first_suppl = lst.iter().position(|i| i.is_suppl_provision()).unwrap();
+  assert!(lst[first_suppl..].iter().all(|i| i.is_suppl_provision()));
+  assert!(lst[..first_suppl].iter().all(|i| !i.is_suppl_provision()));
+}
+
+#[test]
+fn check_article_index_path() {
+  let lst = minpou_index_list();
+  let article = lst
+    .iter()
+    .find(|i| !i.is_suppl_provision() && i.article_number == num("4"))
+    .unwrap();
+  assert_eq!(
+    article
+      .path()
+      .map(|(kind, n)| kind.number_text(n))
+      .collect::<String>(),
+    "第一編第二章第三節"
+  );
+  // 款と目を持つ条（第三編第一章第二節第三款第一目）
+  let article = lst
+    .iter()
+    .find(|i| !i.is_suppl_provision() && i.article_number == num("424"))
+    .unwrap();
+  assert_eq!(
+    article.path().map(|(kind, _)| kind).collect::<Vec<_>>(),
+    vec![
+      StructureKind::Part,
+      StructureKind::Chapter,
+      StructureKind::Section,
+      StructureKind::Subsection,
+      StructureKind::Division
+    ]
+  );
+  assert_eq!(
+    article
+      .path()
+      .map(|(kind, n)| kind.number_text(n))
+      .collect::<String>(),
+    "第三編第一章第二節第三款第一目"
+  );
+}
+
+#[test]
+fn check_article_index_range() {
+  let lst = minpou_index_list();
+  let path = [
+    (StructureKind::Part, num("1")),
+    (StructureKind::Chapter, num("2")),
+    (StructureKind::Section, num("3")),
+  ];
+  let within = lst
+    .iter()
+    .filter(|i| !i.is_suppl_provision() && i.is_within(&path))
+    .collect::<Vec<_>>();
+  assert_eq!(within.len(), 18);
+  assert_eq!(within.first().unwrap().article_number, num("4"));
+  assert_eq!(within.last().unwrap().article_number, num("21"));
+
+  let start = lst.iter().find(|i| i.article_number == num("3")).unwrap();
+  let end = lst.iter().find(|i| i.article_number == num("5")).unwrap();
+  let range = lst
+    .iter()
+    .filter(|i| (start..=end).contains(i))
+    .map(|i| i.article_number.clone())
+    .collect::<Vec<_>>();
+  assert_eq!(range, vec![num("3"), num("3_2"), num("4"), num("5")]);
+}
+
+#[test]
+fn check_article_entry_titles() {
+  let law = minpou();
+  let lst = article_entry_list_from_lawbody("129AC0000000089", "民法", &law.law_body);
+  assert_eq!(
+    lst.len(),
+    article_list_from_lawbody("129AC0000000089", "民法", &law.law_body).len()
+  );
+  let entry = lst
+    .iter()
+    .find(|e| e.article_index.article_number == num("4"))
+    .unwrap();
+  assert_eq!(entry.title.as_deref(), Some("第四条"));
+  assert_eq!(entry.caption.as_deref(), Some("（成年）"));
+  assert_eq!(
+    entry
+      .structure_titles
+      .iter()
+      .map(|t| (t.kind, t.num.clone()))
+      .collect::<Vec<_>>(),
+    entry
+      .article_index
+      .path()
+      .map(|(kind, n)| (kind, n.clone()))
+      .collect::<Vec<_>>()
+  );
+  assert_eq!(
+    entry.breadcrumbs(),
+    vec![
+      "第一編　総則",
+      "第二章　人",
+      "第三節　行為能力",
+      "第四条（成年）"
+    ]
+  );
+
+  let entry = lst
+    .iter()
+    .find(|e| e.article_index.article_number == num("424"))
+    .unwrap();
+  assert_eq!(
+    entry.structure_titles.last().unwrap().title,
+    "第一目　詐害行為取消権の要件"
+  );
+}
+
+#[test]
+fn check_citation_resolver() {
+  let law = minpou();
+  let lst = article_list_from_lawbody("129AC0000000089", "民法", &law.law_body);
+  let article = |n: &str| {
+    lst
+      .iter()
+      .find(|i| !i.article_index.is_suppl_provision() && i.article_index.article_number == num(n))
+      .unwrap()
+      .article_index
+      .clone()
+  };
+  let text_index = |paragraph: &str, items: &[&str]| TextIndex {
+    paragraph: num(paragraph),
+    items: items.iter().map(|s| Some(num(s))).collect(),
+    column: None,
+    table_cell: None,
+    sentence_num: None,
+    sentence_function: None,
+  };
+  let mut resolver = CitationResolver::new(&lst);
+  let mut resolve = |s: &str, current: &ArticleIndex, index: Option<&TextIndex>| {
+    let citation = parse_citation(s).unwrap();
+    resolver.resolve_item(&citation.items[0], current, index)
+  };
+  let locations = |lst: &[(&str, Option<TextIndex>)]| {
+    ResolvedCitation::Locations(
+      lst
+        .iter()
+        .map(|(n, text_index)| ResolvedLocation {
+          article_index: article(n),
+          text_index: text_index.clone(),
+        })
+        .collect(),
+    )
+  };
+
+  let a5 = article("5");
+  assert_eq!(
+    resolve("前項", &a5, Some(&text_index("2", &[]))),
+    Ok(locations(&[("5", Some(text_index("1", &[])))]))
+  );
+  assert_eq!(
+    resolve("前条", &article("6"), None),
+    Ok(locations(&[("5", None)]))
+  );
+  assert_eq!(
+    resolve("次条", &article("3_2"), None),
+    Ok(locations(&[("4", None)]))
+  );
+  assert_eq!(
+    resolve("第三条から第五条まで", &a5, None),
+    Ok(locations(&[
+      ("3", None),
+      ("3_2", None),
+      ("4", None),
+      ("5", None)
+    ]))
+  );
+  assert_eq!(
+    resolve("前各号", &article("13"), Some(&text_index("1", &["3"]))),
+    Ok(locations(&[
+      ("13", Some(text_index("1", &["1"]))),
+      ("13", Some(text_index("1", &["2"]))),
+    ]))
+  );
+  assert_eq!(
+    resolve("第十三条第一項第一号", &a5, None),
+    Ok(locations(&[("13", Some(text_index("1", &["1"])))]))
+  );
+  assert_eq!(
+    resolve("同項第三号", &a5, None),
+    Ok(locations(&[("13", Some(text_index("1", &["3"])))]))
+  );
+  assert_eq!(
+    resolve("この章", &a5, None),
+    Ok(ResolvedCitation::Scope {
+      path: vec![
+        (StructureKind::Part, num("1")),
+        (StructureKind::Chapter, num("2"))
+      ],
+      suppl_provision_name: None,
+    })
+  );
+  assert_eq!(resolve("この法律", &a5, None), Ok(ResolvedCitation::Law));
+  assert_eq!(
+    resolve("同法第五条", &a5, None),
+    Err(ResolveError::OtherLaw)
+  );
+  assert_eq!(resolve("前項", &a5, None), Err(ResolveError::NoContext));
+  assert_eq!(resolve("第九千条", &a5, None), Err(ResolveError::NotFound));
+
+  let mut resolver = CitationResolver::new(&lst);
+  let refs = resolver.references(
+    "前条及び刑法第三条の規定は、第三条から第四条までについて準用する。",
+    &a5,
+    Some(&text_index("1", &[])),
+  );
+  assert_eq!(
+    refs
+      .iter()
+      .map(|r| (
+        r.text.as_str(),
+        r.ref_index.as_ref().map(|i| i.article_number.clone())
+      ))
+      .collect::<Vec<_>>(),
+    vec![
+      ("前条", Some(num("4"))),
+      ("第三条から第四条まで", Some(num("3"))),
+      ("第三条から第四条まで", Some(num("3_2"))),
+      ("第三条から第四条まで", Some(num("4"))),
+    ]
+  );
+  let refs = resolver.references(
+    "刑法（明治四十年法律第四十五号）第三条及び甲（乙をいう。）第四条",
+    &a5,
+    None,
+  );
+  assert_eq!(
+    refs.iter().map(|r| r.text.as_str()).collect::<Vec<_>>(),
+    vec!["第四条"]
+  );
+
+  let paragraphs = &lst.iter().find(|i| i.article_index == a5).unwrap().result;
+  let (index, text, source_map) =
+    text_list_with_source_map(paragraphs, &TextListOptions::default())
+      .into_iter()
+      .find(|(index, _, _)| index.paragraph == num("2"))
+      .unwrap();
+  let refs = resolver.references_with_source_map(&text, &source_map, &a5, Some(&index));
+  assert_eq!(refs.len(), 1);
+  assert_eq!(refs[0].result.text, "前項");
+  assert_eq!(refs[0].text_index_opt, Some(index));
+  let span = refs[0].span.as_ref().unwrap();
+  assert_eq!(&text[span.range.clone()], "前項");
+  assert_eq!(span.sources.len(), 1);
+}
@@ -0,0 +1,266 @@
+//! e-Govの法令データの一括ダウンロードを読み込む
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use jplaw_data_types::article::{text_to_string_with, TextRenderOptions};
+use jplaw_data_types::law::{Date, LawId, LawIdParseError, LawInfo, LawPatchInfo};
+use thiserror::Error;
+use tokio::fs;
+
+use crate::{wran_log, IoError};
+
+/// 走査中に読み飛ばしたファイルの情報
+#[derive(Debug, Error)]
+pub enum LawDirEntryError {
+  /// ファイル名を法令ID・改正年月日・改正法令IDとして解析できなかった
+  #[error("invalid file name {path:?}: {error}")]
+  FileName {
+    path: PathBuf,
+    error: LawIdParseError,
+  },
+  /// XMLを解析できなかったか、法令名や法令番号を得られなかった
+  #[error("invalid law xml {path:?}")]
+  Header { path: PathBuf },
+  /// ファイルやディレクトリの読み込みに失敗した
+  #[error("io error {path:?}: {error}")]
+  Io {
+    path: PathBuf,
+    error: std::io::Error,
+  },
+}
+
+/// 法令データのディレクトリを走査した結果
+#[derive(Debug, Default)]
+pub struct LawDirScan {
+  /// 法令IDの順に並んだ法令のデータ
+  pub laws: Vec<LawInfo>,
+  /// 読み飛ばしたファイル
+  pub errors: Vec<LawDirEntryError>,
+}
+
+/// XMLから得られる情報
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LawXmlHeader {
+  date: Option<Date>,
+  name: String,
+  num: String,
+}
+
+/// XMLを解析し、公布日・法令名・法令番号を得る
+/// 法令名のルビは読みを取り除く
+fn parse_header(xml: &[u8]) -> Option<LawXmlHeader> {
+  let law = japanese_law_xml_schema::parse_xml(xml).ok()?;
+  let date = Date::try_new(law.era, law.year, law.promulgate_month, law.promulgate_day).ok();
+  let name = text_to_string_with(&law.law_body.law_title?.text, &TextRenderOptions::plain());
+  let name = name.trim().to_string();
+  let num = law.law_num.trim().to_string();
+  (!name.is_empty() && !num.is_empty()).then_some(LawXmlHeader { date, name, num })
+}
+
+async fn read_header(path: &Path) -> Result<LawXmlHeader, LawDirEntryError> {
+  let xml = fs::read(path).await.map_err(|error| LawDirEntryError::Io {
+    path: path.to_path_buf(),
+    error,
+  })?;
+  parse_header(&xml).ok_or_else(|| LawDirEntryError::Header {
+    path: path.to_path_buf(),
+  })
+}
+
+/// e-Govの一括ダウンロードで得られるディレクトリを走査し、法令ごとの`LawInfo`のリストを作る関数
+///
+/// `{法令ID}_{改正年月日}_{改正法令ID}.xml`という名前のXMLファイルを全て集め、法令IDごとにまとめる。
+/// 法令名と法令番号は最も新しい版のXMLから読み込む。
+/// 解析できなかったファイルは処理を中断せずに`errors`に記録する。
+pub async fn scan_law_dir<P: AsRef<Path>>(dir: P) -> Result<LawDirScan, IoError> {
+  let mut scan = LawDirScan::default();
+  let mut files: BTreeMap<LawId, Vec<(LawPatchInfo, PathBuf)>> = BTreeMap::new();
+  fs::metadata(dir.as_ref()).await.map_err(IoError::Io)?;
+  let mut stack = vec![dir.as_ref().to_path_buf()];
+  while let Some(dir) = stack.pop() {
+    let mut entries = match fs::read_dir(&dir).await {
+      Ok(entries) => entries,
+      Err(error) => {
+        scan.errors.push(LawDirEntryError::Io { path: dir, error });
+        continue;
+      }
+    };
+    loop {
+      let entry = match entries.next_entry().await {
+        Ok(Some(entry)) => entry,
+        Ok(None) => break,
+        Err(error) => {
+          scan.errors.push(LawDirEntryError::Io {
+            path: dir.clone(),
+            error,
+          });
+          break;
+        }
+      };
+      let path = entry.path();
+      match entry.file_type().await {
+        Ok(t) if t.is_dir() => stack.push(path),
+        Ok(_) => {
+          if path.extension().and_then(|s| s.to_str()) != Some("xml") {
+            continue;
+          }
+          let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+          match LawPatchInfo::from_str(stem) {
+            Ok(patch) => files
+              .entry(patch.id.clone())
+              .or_default()
+              .push((patch, path)),
+            Err(error) => scan.errors.push(LawDirEntryError::FileName { path, error }),
+          }
+        }
+        Err(error) => scan.errors.push(LawDirEntryError::Io { path, error }),
+      }
+    }
+  }
+
+  for (id, mut versions) in files {
+    versions.sort_by(|(a, pa), (b, pb)| a.patch_date.cmp(&b.patch_date).then(pa.cmp(pb)));
+    let Some((_, latest_path)) = versions.last() else {
+      continue;
+    };
+    let header = match read_header(latest_path).await {
+      Ok(header) => header,
+      Err(error) => {
+        scan.errors.push(error);
+        continue;
+      }
+    };
+    let Some(date) = header.date.or_else(|| id.date_hint()) else {
+      scan.errors.push(LawDirEntryError::Header {
+        path: latest_path.clone(),
+      });
+      continue;
+    };
+    scan.laws.push(LawInfo {
+      date,
+      name: header.name,
+      num: header.num,
+      id,
+      patch: versions.into_iter().map(|(patch, _)| patch).collect(),
+    });
+  }
+
+  for error in scan.errors.iter() {
+    wran_log("skip law data", error);
+  }
+  Ok(scan)
+}
+
+#[test]
+fn check_parse_header() {
+  use japanese_law_xml_schema::law::Era;
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Law Era="Showa" Lang="ja" LawType="Act" Num="131" PromulgateDay="02" PromulgateMonth="05" Year="25"><LawNum>昭和二十五年法律第百三十一号</LawNum><LawBody><LawTitle Kana="ほうそうほう" Abbrev="" AbbrevKana="">放送<Ruby>法<Rt>ほう</Rt></Ruby></LawTitle><MainProvision></MainProvision></LawBody></Law>"#;
+  assert_eq!(
+    parse_header(xml.as_bytes()),
+    Some(LawXmlHeader {
+      date: Some(Date::new(Era::Showa, 25, Some(5), Some(2))),
+      name: "放送法".to_string(),
+      num: "昭和二十五年法律第百三十一号".to_string(),
+    })
+  );
+  assert_eq!(parse_header(b"<Law Era=\"Showa\">"), None);
+
+  // 単一引用符や空白を含む属性、CDATA、文字参照も読める
+  let xml = r#"<Law Era = 'Heisei' Lang='ja' LawType='Act' Num='1' Year='1'><LawNum>平成元年法律第一号</LawNum><LawBody><LawTitle>A&amp;B&#x3042;&#12354;<![CDATA[<法>]]></LawTitle><MainProvision></MainProvision></LawBody></Law>"#;
+  let header = parse_header(xml.as_bytes()).unwrap();
+  assert_eq!(header.name, "A&Bああ<法>");
+  assert_eq!(header.date, Some(Date::new(Era::Heisei, 1, None, None)));
+  // 閉じタグが無い場合は途中までの文字列を使わない
+  assert_eq!(
+    parse_header(
+      "<Law Era=\"Heisei\" Year=\"1\"><LawNum>平成元年法律第一号</LawNum><LawBody><LawTitle>途中"
+        .as_bytes()
+    ),
+    None
+  );
+}
+
+#[tokio::test]
+async fn check_scan_law_dir() {
+  use japanese_law_xml_schema::law::Era;
+  let root = std::env::temp_dir().join(format!("jplaw_io_scan_{}", std::process::id()));
+  let _ = fs::remove_dir_all(&root).await;
+  let law_xml = |title: &str| {
+    format!(
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<Law Era="Showa" Lang="ja" LawType="Act" Num="131" PromulgateDay="02" PromulgateMonth="05" Year="25"><LawNum>昭和二十五年法律第百三十一号</LawNum><LawBody><LawTitle>{title}</LawTitle><MainProvision></MainProvision></LawBody></Law>"#
+    )
+  };
+  let long_title = format!(
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<Law Era="Showa" Lang="ja" LawType="Act" Num="132" Year="25"><LawNum>昭和二十五年法律第百三十二号</LawNum><LawBody><LawTitle>{}長い法</LawTitle><MainProvision></MainProvision></LawBody></Law>"#,
+    "<Ruby>あ<Rt>い</Rt></Ruby>".repeat(1000)
+  );
+  let files = [
+    (
+      "325AC0000000131_19500502_000000000000000",
+      law_xml("旧名称法"),
+    ),
+    (
+      "325AC0000000131_20200401_501AC0000000002",
+      law_xml("新名称法"),
+    ),
+    (
+      "325AC0000000131_20150401_426AC0000000069",
+      law_xml("旧名称法"),
+    ),
+    (
+      "325AC0000000132_19500502_000000000000000",
+      "<Law>".to_string(),
+    ),
+    ("325AC0000000133_19500502_000000000000000", long_title),
+    ("325XX0000000131_19500502_000000000000000", law_xml("不正")),
+  ];
+  for (stem, xml) in files.iter() {
+    let dir = root.join("all_xml").join(stem);
+    fs::create_dir_all(&dir).await.unwrap();
+    fs::write(dir.join(format!("{stem}.xml")), xml)
+      .await
+      .unwrap();
+    fs::write(dir.join("pict.jpg"), b"").await.unwrap();
+  }
+
+  let scan = scan_law_dir(&root).await.unwrap();
+  fs::remove_dir_all(&root).await.unwrap();
+
+  assert_eq!(scan.laws.len(), 2);
+  assert_eq!(scan.laws[1].name, format!("{}長い法", "あ".repeat(1000)));
+  let law = &scan.laws[0];
+  assert_eq!(law.id, LawId::from_str("325AC0000000131").unwrap());
+  assert_eq!(law.name, "新名称法");
+  assert_eq!(law.num, "昭和二十五年法律第百三十一号");
+  assert_eq!(law.date, Date::new(Era::Showa, 25, Some(5), Some(2)));
+  assert_eq!(
+    law
+      .patch
+      .iter()
+      .map(|p| p.patch_date.get_ad())
+      .collect::<Vec<_>>(),
+    vec![1950, 2015, 2020]
+  );
+  assert_eq!(scan.errors.len(), 2);
+  assert!(scan
+    .errors
+    .iter()
+    .any(|e| matches!(e, LawDirEntryError::FileName { .. })));
+  assert!(scan
+    .errors
+    .iter()
+    .any(|e| matches!(e, LawDirEntryError::Header { .. })));
+
+  assert!(matches!(
+    scan_law_dir(root.join("not_found")).await,
+    Err(IoError::Io(_))
+  ));
+}
@@ -9,6 +9,8 @@ use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
 use tracing::subscriber::SetGlobalDefaultError;
 use tracing::{error, info, warn};
 
+pub mod egov;
+
 #[derive(Debug, Error)]
 pub enum IoError {
   #[error("io error: {0}")]
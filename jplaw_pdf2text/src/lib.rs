@@ -1,3 +1,4 @@
+use jplaw_data_types::normalize::{normalize, NormalizeOptions};
 use pdf_extract::OutputError;
 use regex::Regex;
 use thiserror::Error;
@@ -37,6 +38,15 @@ pub fn pdf_file_to_text(path: &str) -> Result<String, Pdf2TextError> {
 
 /// ページ番号や行番号などの削除
 pub fn clean_up(text: &str) -> String {
+  clean_up_lines(text, None)
+}
+
+/// ページ番号や行番号などを削除し、設定に従って各行の表記をそろえる
+pub fn clean_up_with(text: &str, options: &NormalizeOptions) -> String {
+  clean_up_lines(text, Some(options))
+}
+
+fn clean_up_lines(text: &str, options: Option<&NormalizeOptions>) -> String {
   // 行番号を表す部分の削除
   // 行末に"5 "のようにテキストとして登場するので取り除く
   let line_number_re = Regex::new("^(?<text>.+?)(5|10|15|20|25) $").unwrap();
@@ -49,11 +59,16 @@ pub fn clean_up(text: &str) -> String {
     } else {
       line.to_string()
     };
+    // 行番号とページ番号は表記をそろえる前の文字列で判定する
     let l = l.trim();
-    if !l.is_empty() && !page_number_re.is_match(l) {
-      s.push_str(l);
-      s.push('\n');
+    if l.is_empty() || page_number_re.is_match(l) {
+      continue;
+    }
+    match options {
+      Some(options) => s.push_str(normalize(l, options).trim()),
+      None => s.push_str(l),
     }
+    s.push('\n');
   }
   s
 }
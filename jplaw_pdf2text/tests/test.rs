@@ -1,3 +1,4 @@
+use jplaw_data_types::normalize::{NormalizeOptions, Width};
 use jplaw_pdf2text::*;
 
 #[test]
@@ -17,3 +18,15 @@ fn check_clean_up() {
   ));
   assert!(s.contains("本件は、商標登録無効審判請求に係る不成立審決の取消訴訟である。争点は、①\n別紙登録商標目録記載の登録商標（以下「本件商標」という。）が商標法４条１項"));
 }
+
+#[test]
+fn check_clean_up_with() {
+  let options = NormalizeOptions {
+    digits: Width::Full,
+    ..Default::default()
+  };
+  assert_eq!(
+    clean_up_with("本文\n- 3 -\n12\n次は25\n第1条 5 ", &options),
+    "本文\n次は２５\n第１条\n"
+  );
+}